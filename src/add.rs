@@ -1,7 +1,9 @@
 use std::borrow::Cow;
 use std::fmt::Display;
+use std::io::IsTerminal;
 use std::process::Command;
 
+use camino::Utf8Component;
 use camino::Utf8Path;
 use camino::Utf8PathBuf;
 use command_error::CommandExt;
@@ -12,25 +14,119 @@ use miette::IntoDiagnostic;
 use owo_colors::OwoColorize;
 use owo_colors::Stream;
 use tracing::instrument;
+use which::which_global;
 
 use crate::app_git::AppGit;
 use crate::cli::AddArgs;
+use crate::exit_code::ErrorCode;
 use crate::final_component;
 use crate::format_bulleted_list::format_bulleted_list;
+use crate::gh::issue_title;
+use crate::gh::slugify;
 use crate::git::BranchRef;
 use crate::git::GitLike;
 use crate::git::LocalBranchRef;
+use crate::git::Ref;
+use crate::git::SparseCheckout;
+use crate::utc_date::utc_today;
 use crate::AddWorktreeOpts;
 use crate::PathDisplay;
 use crate::StatusEntry;
 use crate::Utf8Absolutize;
 
+/// The directory of symlinks to sibling worktrees created by `add --link-worktrees`.
+const LINKS_DIR: &str = ".worktrees";
+
+/// Create a worktree, or one worktree for each name with `--multiple`.
+pub fn add(git: AppGit<'_, Utf8PathBuf>, args: &AddArgs) -> miette::Result<()> {
+    if args.set_head_remote {
+        // Test: `add_set_head_remote`
+        match git.remote().preferred()? {
+            Some(remote) => {
+                let branch = git.remote().set_head(&remote)?;
+                tracing::info!("Set {remote}/HEAD to {}", branch.qualified_branch_name());
+            }
+            None => {
+                tracing::warn!("No remote to set HEAD for");
+            }
+        }
+    }
+
+    if !args.multiple {
+        return WorktreePlan::new(git, args)?.execute();
+    }
+
+    // Test: `add_multiple`
+    let names = args
+        .inner
+        .name_or_path
+        .iter()
+        .chain(&args.commitish)
+        .chain(&args.more_names);
+    let all_args = names
+        .map(|name| {
+            let mut args = args.clone();
+            args.inner.name_or_path = Some(name.clone());
+            args.commitish = None;
+            args.more_names = Vec::new();
+            args.multiple = false;
+            args
+        })
+        .collect::<Vec<_>>();
+
+    let mut errors = Vec::new();
+    for args in &all_args {
+        let name = args
+            .inner
+            .name_or_path
+            .as_deref()
+            .expect("Every name is set above");
+        let result = WorktreePlan::new(git.clone(), args).and_then(|plan| plan.execute());
+        match result {
+            Ok(()) => {}
+            Err(error) if args.keep_going => {
+                // Test: `add_multiple_keep_going`
+                tracing::error!("Failed to add worktree {name}: {error:?}");
+                errors.push(name);
+            }
+            Err(error) => {
+                return Err(error.wrap_err(format!("Failed to add worktree {name}")));
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(miette!(
+            "Failed to add {} of {} worktrees:\n{}",
+            errors.len(),
+            all_args.len(),
+            format_bulleted_list(errors)
+        ))
+    }
+}
+
 /// A plan for creating a new `git worktree`.
 #[derive(Debug, Clone)]
 pub struct WorktreePlan<'a> {
     git: AppGit<'a, Utf8PathBuf>,
     destination: Utf8PathBuf,
     branch: BranchStartPointPlan,
+    description: Option<&'a str>,
+    tmux: bool,
+    no_checkout: bool,
+    require_clean: bool,
+    link_worktrees: bool,
+    sparse_checkout: Option<SparseCheckout>,
+    copy_ignored_symlinks: bool,
+    hooks_dir: Option<&'a Utf8Path>,
+    code_workspace: Option<Utf8PathBuf>,
+    lock_reason: Option<String>,
+    /// The message for an empty commit to make on the new branch, if any.
+    empty_commit: Option<String>,
+    no_verify: bool,
+    worktree_add_args: &'a [String],
     copy_ignored: Vec<StatusEntry>,
 }
 
@@ -43,6 +139,34 @@ impl Display for WorktreePlan<'_> {
             self.branch,
         )?;
 
+        if let Some(hooks_dir) = self.hooks_dir {
+            write!(f, "\nSetting `core.hooksPath` to {hooks_dir}")?;
+        }
+
+        if let Some(code_workspace) = &self.code_workspace {
+            write!(
+                f,
+                "\nAdding worktree to VS Code workspace {}",
+                code_workspace.display_path_cwd()
+            )?;
+        }
+
+        if let Some(sparse_checkout) = &self.sparse_checkout {
+            write!(f, "\nUsing sparse-checkout {sparse_checkout}")?;
+        }
+
+        if self.link_worktrees {
+            write!(f, "\nLinking worktrees to each other in `{LINKS_DIR}/`")?;
+        }
+
+        if let Some(reason) = &self.lock_reason {
+            write!(f, "\nLocking worktree: {reason}")?;
+        }
+
+        if let Some(message) = &self.empty_commit {
+            write!(f, "\nCommitting an empty commit: {message}")?;
+        }
+
         if !self.copy_ignored.is_empty() {
             write!(
                 f,
@@ -72,40 +196,512 @@ impl<'a> WorktreePlan<'a> {
         let worktree = git.worktree().find_some()?;
 
         let git = git.with_current_dir(worktree);
-        let branch = BranchStartPointPlan::new(&git, args)?;
-        let destination = Self::destination_plan(&git, args, &branch)?;
+        Self::check_first_worktree(&git)?;
+        let from_head_of = Self::from_head_of_plan(&git, args)?;
+        let fetched = Self::fetch_plan(&git, args)?;
+        let picked = Self::pick_base_plan(&git, args)?;
+        let commitish = from_head_of
+            .as_deref()
+            .or(fetched.as_deref())
+            .or(picked.as_deref())
+            .or(args.commitish.as_deref());
+        let branch = BranchStartPointPlan::new(&git, args, commitish)?;
+        let (branch, busy_branch) = Self::detach_if_branch_busy(&git, args, branch)?;
+        Self::explain_branch_plan(&git, args, commitish, &branch);
+        Self::check_force_branch_not_checked_out(&git, &branch)?;
+        let destination = match &busy_branch {
+            Some(busy_branch)
+                if args.dir.is_none()
+                    && args
+                        .inner
+                        .name_or_path
+                        .as_deref()
+                        .is_none_or(|name| name == busy_branch.branch_name()) =>
+            {
+                // Test: `add_checkout_detach_if_branch_busy`
+                git.worktree()
+                    .path_for(&format!("{}-detached", busy_branch.branch_name()))?
+            }
+            _ => Self::destination_plan(&git, args, &branch)?,
+        };
+        Self::check_destination_name(&destination)?;
         let copy_ignored = Self::copy_ignored_plan(&git)?;
+        let hooks_dir = args
+            .hooks_dir
+            .as_deref()
+            .or_else(|| git.config.file.add.hooks_dir());
+
+        if args.description.is_some() && !matches!(branch, BranchStartPointPlan::New { .. }) {
+            return Err(miette!(
+                "`--description` can only be used when creating a new branch"
+            ));
+        }
+
+        let empty_commit = match (&branch, args.empty) {
+            (_, false) => None,
+            (BranchStartPointPlan::New { branch, .. }, true) => {
+                Some(format!("Start {}", branch.branch_name()))
+            }
+            (_, true) => {
+                return Err(miette!(
+                    "`--empty` can only be used when creating a new branch"
+                ));
+            }
+        };
+
+        let code_workspace = Self::code_workspace_plan(&git, args)?;
+        let sparse_checkout = Self::sparse_checkout_plan(&git, args)?;
+        let link_worktrees = args
+            .link_worktrees()
+            .unwrap_or_else(|| git.config.file.add.link_siblings());
+        let copy_ignored_symlinks = args
+            .copy_ignored_symlinks()
+            .unwrap_or_else(|| git.config.file.add.copy_ignored_symlinks());
+        let lock_reason = Self::lock_reason_plan(&git, args, &destination, &branch);
+        Self::check_worktree_add_args(args);
+
         Ok(Self {
             git,
             branch,
             destination,
+            description: args.description.as_deref(),
+            tmux: args.tmux,
+            no_checkout: args.no_checkout,
+            require_clean: args.require_clean,
+            link_worktrees,
+            sparse_checkout,
+            copy_ignored_symlinks,
+            hooks_dir,
+            code_workspace,
+            lock_reason,
+            empty_commit,
+            no_verify: args.no_verify,
+            worktree_add_args: &args.worktree_add_args,
             copy_ignored,
         })
     }
 
-    #[instrument(level = "trace")]
-    fn copy_ignored_plan(git: &AppGit<'_, Utf8PathBuf>) -> miette::Result<Vec<StatusEntry>> {
-        if git.config.file.add.copy_ignored() && git.worktree().is_inside()? {
-            Ok(git
-                .status()
-                .get()?
-                .into_iter()
-                .filter(|entry| entry.is_ignored())
-                .collect())
+    /// Get the current worktree's sparse-checkout patterns, if `--inherit-sparse` is given.
+    fn sparse_checkout_plan(
+        git: &AppGit<'_, Utf8PathBuf>,
+        args: &AddArgs,
+    ) -> miette::Result<Option<SparseCheckout>> {
+        if !args.inherit_sparse {
+            return Ok(None);
+        }
+
+        let sparse_checkout = git.sparse_checkout().get()?;
+        if sparse_checkout.is_none() {
+            // Test: `add_inherit_sparse_dense`
+            tracing::warn!(
+                "{} doesn't use sparse-checkout; checking out all files",
+                git.get_current_dir().display_path_cwd()
+            );
+        }
+        Ok(sparse_checkout)
+    }
+
+    /// Determine the VS Code workspace file to add the new worktree to, if any.
+    fn code_workspace_plan(
+        git: &AppGit<'_, Utf8PathBuf>,
+        args: &AddArgs,
+    ) -> miette::Result<Option<Utf8PathBuf>> {
+        if let Some(path) = &args.add_to_workspace {
+            // Test: `add_to_workspace`
+            return Ok(Some(path.absolutize().into_diagnostic()?.into_owned()));
+        }
+
+        match git.config.file.add.code_workspace() {
+            // Test: `config_add_code_workspace`
+            Some(path) => Ok(Some(git.worktree().container()?.join(path))),
+            None => Ok(None),
+        }
+    }
+
+    /// Ask the user to pick a branch to start the new worktree at, for `--pick-base`.
+    fn pick_base_plan(
+        git: &AppGit<'_, Utf8PathBuf>,
+        args: &AddArgs,
+    ) -> miette::Result<Option<String>> {
+        /// The most branches to list.
+        const MAX_BRANCHES: usize = 20;
+
+        if !args.pick_base {
+            return Ok(None);
+        }
+
+        if !std::io::stdin().is_terminal() {
+            // Test: `add_pick_base_not_a_tty`
+            return Err(miette!(
+                "`--pick-base` needs an interactive terminal; pass a COMMITISH to start the new \
+                 worktree at instead"
+            ));
+        }
+
+        let branches = git
+            .refs()
+            .for_each_ref_sorted(Some("-committerdate"), Some(&["refs/heads/**"]))?;
+        if branches.is_empty() {
+            return Err(miette!("No local branches to pick a starting point from"));
+        }
+        let branches = &branches[..branches.len().min(MAX_BRANCHES)];
+
+        for (i, branch) in branches.iter().enumerate() {
+            eprintln!(
+                "{:>3}. {} {}",
+                i + 1,
+                branch
+                    .name()
+                    .if_supports_color(Stream::Stderr, |text| text.cyan()),
+                git.refs()
+                    .subject(&format!("{branch:#}"))?
+                    .if_supports_color(Stream::Stderr, |text| text.dimmed())
+            );
+        }
+        eprint!("Start the new worktree at [1-{}]: ", branches.len());
+
+        let mut choice = String::new();
+        std::io::stdin()
+            .read_line(&mut choice)
+            .into_diagnostic()
+            .wrap_err("Failed to read choice")?;
+        let branch = choice
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .and_then(|choice| branches.get(choice.checked_sub(1)?))
+            .ok_or_else(|| miette!("Invalid choice: {}", choice.trim()))?;
+
+        Ok(Some(branch.name().to_owned()))
+    }
+
+    /// Explain how the arguments were interpreted to pick a branch and start point.
+    ///
+    /// This is logged under `--dry-run`, or with `--log debug` otherwise.
+    fn explain_branch_plan(
+        git: &AppGit<'_, Utf8PathBuf>,
+        args: &AddArgs,
+        commitish: Option<&str>,
+        branch: &BranchStartPointPlan,
+    ) {
+        let mut inputs = Vec::new();
+        if let Some(branch) = &args.inner.branch {
+            inputs.push(format!("--branch={branch}"));
+        }
+        if let Some(branch) = &args.inner.force_branch {
+            inputs.push(format!("--force-branch={branch}"));
+        }
+        if let Some(issue) = args.inner.issue {
+            inputs.push(format!("--issue={issue}"));
+        }
+        if args.inner.detach {
+            inputs.push("--detach".to_owned());
+        }
+        if let Some(name_or_path) = &args.inner.name_or_path {
+            inputs.push(format!("NAME_OR_PATH={name_or_path}"));
+        }
+        if let Some(commitish) = commitish {
+            inputs.push(format!("START_POINT={commitish}"));
+        }
+
+        let decision = match branch {
+            BranchStartPointPlan::Existing(branch) => {
+                format!(
+                    "{} is a local branch, checking it out",
+                    branch.branch_name()
+                )
+            }
+            BranchStartPointPlan::New { branch, start, .. } => {
+                let branch = branch.branch_name();
+                let start = match start {
+                    StartPoint::Branch(BranchRef::Local(local)) => {
+                        format!("local branch {}", local.branch_name())
+                    }
+                    StartPoint::Branch(BranchRef::Remote(remote)) => {
+                        format!("remote branch {}", remote.qualified_branch_name())
+                    }
+                    StartPoint::Tag(tag) => format!("tag {}", tag.name()),
+                    StartPoint::Commitish(commitish) => format!("commit {commitish}"),
+                };
+                match commitish {
+                    Some(commitish) => {
+                        format!(
+                            "START_POINT={commitish} resolves to {start}, creating branch {branch}"
+                        )
+                    }
+                    None if args.inner.branch.is_none() && args.inner.force_branch.is_none() => {
+                        format!("{branch} is not a local branch, creating it at {start}")
+                    }
+                    None => format!("No START_POINT given, creating branch {branch} at {start}"),
+                }
+            }
+            BranchStartPointPlan::Detach(start) => {
+                format!("Detaching HEAD at {}", start.commitish())
+            }
+        };
+
+        // Test: `add_dry_run_explain`
+        if git.config.cli.dry_run {
+            tracing::info!("Interpreting {} as: {decision}", inputs.join(", "));
         } else {
-            Ok(Vec::new())
+            tracing::debug!("Interpreting {} as: {decision}", inputs.join(", "));
+        }
+    }
+
+    /// Determine the reason to lock the new worktree with, if it should be locked.
+    fn lock_reason_plan(
+        git: &AppGit<'_, Utf8PathBuf>,
+        args: &AddArgs,
+        destination: &Utf8Path,
+        branch: &BranchStartPointPlan,
+    ) -> Option<String> {
+        if let Some(reason) = &args.lock_reason {
+            // Test: `add_lock_reason`
+            return Some(reason.clone());
+        }
+
+        if !args.lock {
+            return None;
+        }
+
+        // Tests:
+        // - `config_add_lock_reason_template`
+        // - `config_add_lock_reason_template_default`
+        let (name, branch) = template_name_and_branch(destination, branch);
+        Some(
+            git.config
+                .file
+                .add
+                .lock_reason_template()
+                .replace("{date}", &utc_today())
+                .replace("{name}", name)
+                .replace("{branch}", branch),
+        )
+    }
+
+    /// Let the user know if this will be the first worktree in a bare repository.
+    ///
+    /// This happens if a bare repository was created by hand rather than with `git prole
+    /// clone` or `git prole convert`.
+    #[instrument(level = "trace")]
+    fn check_first_worktree(git: &AppGit<'_, Utf8PathBuf>) -> miette::Result<()> {
+        let worktrees = git.worktree().list()?;
+        if worktrees.main().head.is_bare() && worktrees.len() == 1 {
+            // Tests:
+            // - `add_bare_no_worktrees`
+            // - `add_first_worktree_in_bare`
+            tracing::info!(
+                "Repository {} has no worktrees yet; creating its first worktree",
+                worktrees.main_path().display_path_cwd()
+            );
+        }
+        Ok(())
+    }
+
+    /// Refuse to reset a branch with `--force-branch` if it's checked out in another worktree.
+    ///
+    /// Resetting the branch would leave that worktree's files out of sync with its `HEAD`.
+    #[instrument(level = "trace")]
+    fn check_force_branch_not_checked_out(
+        git: &AppGit<'_, Utf8PathBuf>,
+        branch: &BranchStartPointPlan,
+    ) -> miette::Result<()> {
+        let BranchStartPointPlan::New {
+            force: true,
+            branch,
+            ..
+        } = branch
+        else {
+            return Ok(());
+        };
+
+        if let Some(worktree) = git.worktree().list()?.for_branch(branch) {
+            // Test: `add_branch_force_checked_out`
+            return Err(miette!(
+                "Branch {} is checked out in worktree {}; not resetting it with `--force-branch`",
+                branch
+                    .branch_name()
+                    .if_supports_color(Stream::Stdout, |text| text.cyan()),
+                worktree.path.display_path_cwd()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// With `--checkout-detach-if-branch-busy`, if the branch to check out is already checked out
+    /// in another worktree, detach at its commit instead.
+    ///
+    /// Returns the new plan and the busy branch, if the plan was changed.
+    #[instrument(level = "trace")]
+    fn detach_if_branch_busy(
+        git: &AppGit<'_, Utf8PathBuf>,
+        args: &AddArgs,
+        branch: BranchStartPointPlan,
+    ) -> miette::Result<(BranchStartPointPlan, Option<LocalBranchRef>)> {
+        let BranchStartPointPlan::Existing(existing) = &branch else {
+            return Ok((branch, None));
+        };
+        if !args.checkout_detach_if_branch_busy {
+            return Ok((branch, None));
+        }
+
+        let worktrees = git.worktree().list()?;
+        let Some(worktree) = worktrees.for_branch(existing) else {
+            return Ok((branch, None));
+        };
+        let Some(commit) = worktree.head.commit() else {
+            return Ok((branch, None));
+        };
+
+        // Test: `add_checkout_detach_if_branch_busy`
+        tracing::info!(
+            "Branch {} is checked out in {}; creating a detached worktree at {commit} instead",
+            existing
+                .branch_name()
+                .if_supports_color(Stream::Stdout, |text| text.cyan()),
+            worktree.path.display_path_cwd(),
+        );
+        let existing = existing.clone();
+        Ok((
+            BranchStartPointPlan::Detach(StartPoint::Commitish(format!("{commit:#}"))),
+            Some(existing),
+        ))
+    }
+
+    /// Warn about extra `git worktree add` arguments which conflict with the arguments we
+    /// generate.
+    fn check_worktree_add_args(args: &AddArgs) {
+        for arg in &args.worktree_add_args {
+            let flag = arg
+                .split_once('=')
+                .map_or(arg.as_str(), |(flag, _value)| flag);
+            if let Some(option) = MANAGED_WORKTREE_ADD_ARGS
+                .iter()
+                .find_map(|(managed, option)| (*managed == flag).then_some(option))
+            {
+                // Test: `add_worktree_add_args`
+                tracing::warn!(
+                    "`git worktree add` argument {} may conflict with the generated command; \
+                     use `{option}` instead",
+                    arg.if_supports_color(Stream::Stdout, |text| text.cyan()),
+                );
+            }
+        }
+    }
+
+    /// Resolve `--from-head-of` into the commit checked out in the given worktree.
+    #[instrument(level = "trace")]
+    fn from_head_of_plan(
+        git: &AppGit<'_, Utf8PathBuf>,
+        args: &AddArgs,
+    ) -> miette::Result<Option<String>> {
+        let Some(name_or_path) = &args.from_head_of else {
+            return Ok(None);
+        };
+
+        // Test: `add_from_head_of`
+        let worktrees = git.worktree().list()?;
+        let worktree = worktrees.resolve(name_or_path)?;
+        match worktree.head.commit() {
+            Some(commit) if !commit.is_null() => Ok(Some(format!("{commit:#}"))),
+            _ => Err(miette!(
+                "Worktree {} has no commit checked out",
+                worktree.path.display_path_cwd()
+            )),
         }
     }
 
+    /// Fetch the branch given by `--fetch`, returning the fetched commit.
+    #[instrument(level = "trace")]
+    fn fetch_plan(git: &AppGit<'_, Utf8PathBuf>, args: &AddArgs) -> miette::Result<Option<String>> {
+        let Some(url_branch) = &args.fetch else {
+            return Ok(None);
+        };
+
+        // Test: `add_fetch`
+        tracing::info!(
+            "Fetching {}",
+            url_branch.if_supports_color(Stream::Stdout, |text| text.cyan())
+        );
+        git.remote().fetch(
+            &url_branch.url,
+            Some(&format!("{:#}", LocalBranchRef::from(&url_branch.branch))),
+        )?;
+        let commit = git
+            .refs()
+            .parse("FETCH_HEAD")?
+            .ok_or_else(|| miette!("Failed to fetch {url_branch}"))?;
+        Ok(Some(format!("{commit:#}")))
+    }
+
+    #[instrument(level = "trace")]
+    pub(crate) fn copy_ignored_plan(
+        git: &AppGit<'_, Utf8PathBuf>,
+    ) -> miette::Result<Vec<StatusEntry>> {
+        if !git.config.file.add.copy_ignored() {
+            return Ok(Vec::new());
+        }
+
+        if !git.worktree().is_inside()? {
+            // There's no worktree to copy from, e.g. when adding the first worktree in a bare
+            // repository.
+            //
+            // Test: `add_first_worktree_in_bare`
+            tracing::debug!("Not in a worktree; not copying ignored files");
+            return Ok(Vec::new());
+        }
+
+        let include = git.config.file.add.copy_ignored_include();
+        let exclude = git.config.file.add.copy_ignored_exclude();
+        let status = git.status().get()?;
+        Ok(status
+            .ignored()
+            // The new worktree gets its own links to its siblings.
+            //
+            // Test: `add_link_worktrees`
+            .filter(|entry| entry.path.as_str().trim_end_matches('/') != LINKS_DIR)
+            .filter(|entry| {
+                // Tests:
+                // - `config_add_copy_ignored_include`
+                // - `config_add_copy_ignored_exclude`
+                let path = entry.path.as_str();
+                (include.is_empty() || include.iter().any(|glob| glob.is_match(path)))
+                    && !exclude.iter().any(|glob| glob.is_match(path))
+            })
+            .cloned()
+            .collect())
+    }
+
     #[instrument(level = "trace")]
     fn destination_plan(
         git: &AppGit<'_, Utf8PathBuf>,
         args: &AddArgs,
         branch: &BranchStartPointPlan,
     ) -> miette::Result<Utf8PathBuf> {
+        if let Some(dir) = &args.dir {
+            if dir.contains('/') {
+                return Err(miette!(
+                    "`--dir` must be a directory name, not a path: {dir}"
+                ));
+            }
+            if Utf8Path::new(dir)
+                .components()
+                .any(|component| component == Utf8Component::ParentDir)
+            {
+                // Test: `add_dir_parent`
+                return Err(miette!("`--dir` can't leave the worktree container: {dir}"));
+            }
+            // Test case: `add_dir`.
+            return Ok(git.worktree().container()?.join(dir));
+        }
+
         Ok(match &args.inner.name_or_path {
             Some(name_or_path) => {
-                if name_or_path.contains('/') {
+                if let Some(subpath) = name_or_path.strip_prefix("@/") {
+                    // Test case: `add_container_relative`.
+                    git.worktree().container()?.join(subpath)
+                } else if name_or_path.contains('/') {
                     // Test case: `add_by_path`.
                     Utf8Path::new(name_or_path)
                         .absolutize()
@@ -116,31 +712,43 @@ impl<'a> WorktreePlan<'a> {
                     git.worktree().path_for(name_or_path)?
                 }
             }
-            None => {
-                let name = match branch {
-                    BranchStartPointPlan::New { branch, .. }
-                    | BranchStartPointPlan::Existing(branch) => branch.branch_name(),
-                    BranchStartPointPlan::Detach(start) => start.commitish(),
-                };
-                // Test case: `add_branch_new_local`.
-                git.worktree().path_for(name)?
-            }
+            None => match branch {
+                BranchStartPointPlan::New { branch, .. }
+                | BranchStartPointPlan::Existing(branch) => {
+                    // Test case: `add_branch_new_local`.
+                    git.worktree().path_for(branch.branch_name())?
+                }
+                // Test case: `config_add_scratch_prefix`.
+                BranchStartPointPlan::Detach(_) => git.worktree().scratch_path()?,
+            },
         })
     }
 
+    /// Check that the new worktree's directory name won't be confused with a Git directory, like
+    /// the bare `.git` directory in the worktree container.
+    fn check_destination_name(destination: &Utf8Path) -> miette::Result<()> {
+        match destination.file_name() {
+            Some(name) if name.starts_with(".git") => {
+                // Test: `add_dot_git_name`
+                Err(miette!(
+                    "Worktree directory names can't start with `.git`: {}\n\
+                     Use `--branch BRANCH --dir NAME` to choose the directory name separately",
+                    destination.display_path_cwd()
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn command(&self) -> Command {
-        let (force_branch, track, create_branch) = match &self.branch {
+        let (force_branch, new_branch_start, create_branch) = match &self.branch {
             BranchStartPointPlan::New {
                 force,
                 branch,
                 start,
-            } => {
-                let track = matches!(start, StartPoint::Branch(_));
-
-                (*force, track, Some(branch))
-            }
+            } => (*force, Some(start), Some(branch)),
             BranchStartPointPlan::Detach(_) | BranchStartPointPlan::Existing(_) => {
-                (false, false, None)
+                (false, None, None)
             }
         };
 
@@ -149,18 +757,360 @@ impl<'a> WorktreePlan<'a> {
             &AddWorktreeOpts {
                 force_branch,
                 create_branch,
-                track,
+                track: matches!(new_branch_start, Some(StartPoint::Branch(_))),
+                // Tags can't be tracked, even with `branch.autoSetupMerge=always`.
+                //
+                // Test: `add_branch_from_tag`
+                no_track: matches!(new_branch_start, Some(StartPoint::Tag(_))),
                 start_point: Some(match &self.branch {
                     BranchStartPointPlan::Existing(branch) => branch.branch_name(),
                     BranchStartPointPlan::New { start, .. } => start.commitish(),
                     BranchStartPointPlan::Detach(start) => start.commitish(),
                 }),
                 detach: matches!(self.branch, BranchStartPointPlan::Detach(_)),
+                checkout: !self.no_checkout,
+                lock: self.lock_reason.as_deref(),
+                extra_args: self.worktree_add_args,
                 ..Default::default()
             },
         )
     }
 
+    #[instrument(level = "trace")]
+    fn set_description(&self) -> miette::Result<()> {
+        if let (Some(description), BranchStartPointPlan::New { branch, .. }) =
+            (self.description, &self.branch)
+        {
+            // Test: `add_branch_description`
+            self.git.config().set(
+                &format!("branch.{}.description", branch.branch_name()),
+                description,
+            )?;
+        }
+        Ok(())
+    }
+
+    #[instrument(level = "trace")]
+    fn set_hooks_dir(&self) -> miette::Result<()> {
+        if let Some(hooks_dir) = self.hooks_dir {
+            // Tests:
+            // - `add_hooks_dir`
+            // - `config_add_hooks_dir`
+            self.git
+                .with_current_dir(&self.destination)
+                .config()
+                .set_worktree("core.hooksPath", hooks_dir.as_str())?;
+        }
+        Ok(())
+    }
+
+    /// Create `.worktrees/` directories of symlinks between all the worktrees.
+    #[instrument(level = "trace")]
+    fn link_worktrees(&self) -> miette::Result<()> {
+        if !self.link_worktrees {
+            return Ok(());
+        }
+
+        // Tests:
+        // - `add_link_worktrees`
+        // - `config_add_link_siblings`
+        let container = self.git.worktree().container()?;
+        let worktrees = self.git.worktree().list()?;
+        let paths = worktrees
+            .values()
+            .filter(|worktree| !worktree.head.is_bare())
+            .map(|worktree| worktree.path.as_path())
+            .collect::<Vec<_>>();
+
+        for worktree in &paths {
+            let links = worktree.join(LINKS_DIR);
+            if !links.exists() {
+                crate::fs::create_dir(&links)?;
+                crate::fs::write(links.join(".gitignore"), "*\n")?;
+            }
+
+            for sibling in paths.iter().filter(|sibling| *sibling != worktree) {
+                // Links are named by their path in the container, so that `feature/x` and
+                // `bugfix/x` don't collide.
+                //
+                // Test: `add_link_worktrees_nested`
+                let link = links.join(
+                    sibling
+                        .strip_prefix(&container)
+                        .unwrap_or_else(|_| Utf8Path::new(final_component(sibling.as_str()))),
+                );
+                let link_dir = link.parent().unwrap_or(&links);
+                let target = pathdiff::diff_utf8_paths(sibling, link_dir)
+                    .unwrap_or_else(|| sibling.to_path_buf());
+                if link.symlink_metadata().is_ok() {
+                    match link.read_link_utf8() {
+                        Ok(existing) if existing == target => {
+                            tracing::debug!(%link, "Worktree link already exists");
+                        }
+                        _ => {
+                            tracing::warn!(
+                                "{} already exists; not linking it to {}",
+                                link.display_path_cwd(),
+                                sibling.display_path_cwd(),
+                            );
+                        }
+                    }
+                    continue;
+                }
+                crate::fs::create_dir_all(link_dir)?;
+                crate::fs::symlink(target, link)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[instrument(level = "trace")]
+    pub fn execute(&self) -> miette::Result<()> {
+        let mut command = self.command();
+
+        // Test: `add_destination_exists`
+        if self.destination.exists() {
+            return Err(miette!(
+                "Worktree destination {} already exists",
+                self.destination.display_path_cwd()
+            ));
+        }
+
+        tracing::info!("{self}");
+        tracing::debug!("{self:#?}");
+
+        if self.git.config.cli.dry_run {
+            tracing::info!(
+                "{} {}",
+                '$'.if_supports_color(Stream::Stdout, |text| text.green()),
+                Utf8ProgramAndArgs::from(&command)
+            );
+            return Ok(());
+        }
+
+        command.status_checked()?;
+        if let Some(sparse_checkout) = &self.sparse_checkout {
+            // Test: `add_inherit_sparse`
+            self.git
+                .with_current_dir(&self.destination)
+                .sparse_checkout()
+                .set(sparse_checkout)?;
+        }
+        self.set_description()?;
+        self.set_hooks_dir()?;
+        if let Some(code_workspace) = &self.code_workspace {
+            crate::code_workspace::add_folder(code_workspace, &self.destination)?;
+        }
+        self.link_worktrees()?;
+        if self.no_checkout {
+            // Test: `add_no_checkout_populate`
+            tracing::info!(
+                "Run `git prole populate {}` to check out files",
+                self.destination.display_path_cwd()
+            );
+        } else {
+            self.setup().execute()?;
+            self.commit_empty()?;
+            self.check_clean()?;
+        }
+        self.open_tmux_window()?;
+        Ok(())
+    }
+
+    #[instrument(level = "trace")]
+    fn commit_empty(&self) -> miette::Result<()> {
+        if let Some(message) = &self.empty_commit {
+            // Tests:
+            // - `add_empty`
+            // - `add_empty_no_verify`
+            let mut command = self.git.with_current_dir(&self.destination).command();
+            command.args(["commit", "--allow-empty", "--message", message]);
+            if self.no_verify {
+                command.arg("--no-verify");
+            }
+            command.status_checked()?;
+        }
+        Ok(())
+    }
+
+    #[instrument(level = "trace")]
+    fn check_clean(&self) -> miette::Result<()> {
+        if !self.require_clean {
+            return Ok(());
+        }
+
+        let status = self
+            .git
+            .with_current_dir(self.destination.as_path())
+            .status()
+            .get()?;
+        if status.is_clean() {
+            return Ok(());
+        }
+
+        // Test: `add_require_clean`
+        Err(ErrorCode::DirtyWorktree.error(format!(
+            "Worktree {} has modified files after setup:\n{}",
+            self.destination.display_path_cwd(),
+            format_bulleted_list(status.modified())
+        )))
+    }
+
+    fn setup(&self) -> WorktreeSetup<'a, '_> {
+        WorktreeSetup {
+            git: &self.git,
+            destination: &self.destination,
+            copy_ignored: &self.copy_ignored,
+            copy_ignored_symlinks: self.copy_ignored_symlinks,
+        }
+    }
+
+    #[instrument(level = "trace")]
+    fn open_tmux_window(&self) -> miette::Result<()> {
+        if !self.tmux {
+            return Ok(());
+        }
+
+        if std::env::var_os("TMUX").is_none() {
+            // Test: `add_tmux_outside_tmux`
+            tracing::warn!("Not running inside `tmux`; not opening a window for the new worktree");
+            return Ok(());
+        }
+
+        let (name, branch) = template_name_and_branch(&self.destination, &self.branch);
+        let window_name = self
+            .git
+            .config
+            .file
+            .add
+            .tmux_window_name()
+            .replace("{name}", name)
+            .replace("{branch}", branch);
+
+        // Tests:
+        // - `add_tmux`
+        // - `config_add_tmux_window_name`
+        Command::new("tmux")
+            .args([
+                "new-window",
+                "-n",
+                &window_name,
+                "-c",
+                self.destination.as_str(),
+            ])
+            .status_checked()
+            .into_diagnostic()
+            .wrap_err("Failed to open a `tmux` window for the new worktree")?;
+
+        Ok(())
+    }
+}
+
+/// `git worktree add` arguments which `git prole add` sets itself, and the `git prole add` options
+/// to use instead.
+const MANAGED_WORKTREE_ADD_ARGS: &[(&str, &str)] = &[
+    ("-b", "git prole add --branch"),
+    ("-B", "git prole add --force-branch"),
+    ("-d", "git prole add --detach"),
+    ("--detach", "git prole add --detach"),
+    ("--checkout", "git prole add"),
+    ("--no-checkout", "git prole add --no-checkout"),
+    ("--track", "git prole add COMMITISH"),
+    ("--no-track", "git prole add COMMITISH"),
+    ("--guess-remote", "git prole add NAME"),
+    ("--no-guess-remote", "git prole add NAME"),
+    ("--reason", "git prole add --lock-reason"),
+];
+
+/// The name of a new branch to create, after applying `add.branch_name_replacements`.
+fn new_branch_name(git: &AppGit<'_, Utf8PathBuf>, branch: &str) -> LocalBranchRef {
+    // Tests:
+    // - `config_branch_name_replacements`
+    // - `config_branch_name_replacements_default`
+    let replaced = git
+        .config
+        .file
+        .add
+        .branch_name_replacements()
+        .iter()
+        .fold(branch.to_owned(), |name, replacement| {
+            replacement.apply(&name)
+        });
+    if replaced != branch {
+        tracing::debug!(%branch, %replaced, "Applied `add.branch_name_replacements`");
+    }
+    LocalBranchRef::new(replaced)
+}
+
+/// The name of the branch to create for `add --issue`.
+fn issue_branch_name(git: &AppGit<'_, Utf8PathBuf>, issue: u64) -> String {
+    let name = git
+        .config
+        .file
+        .add
+        .issue_branch_template()
+        .replace("{n}", &issue.to_string());
+    match issue_title(issue).map(|title| slugify(&title)) {
+        // Test: `add_issue_title`
+        Some(slug) if !slug.is_empty() => format!("{name}-{slug}"),
+        // Tests:
+        // - `add_issue`
+        // - `config_add_issue_branch_template`
+        _ => name,
+    }
+}
+
+/// If `commitish` names a tag or branch, get its ref.
+fn named_ref(git: &AppGit<'_, Utf8PathBuf>, commitish: &str) -> miette::Result<Option<Ref>> {
+    Ok(git
+        .refs()
+        .rev_parse_symbolic_full_name(commitish)?
+        .filter(|ref_name| {
+            ref_name.is_tag() || ref_name.is_local_branch() || ref_name.is_remote_branch()
+        }))
+}
+
+/// The `{name}` and `{branch}` to substitute into templates like `add.tmux_window_name`.
+///
+/// If `HEAD` will be detached, the name is used as the branch.
+fn template_name_and_branch<'b>(
+    destination: &'b Utf8Path,
+    branch: &'b BranchStartPointPlan,
+) -> (&'b str, &'b str) {
+    let name = final_component(destination.as_str());
+    let branch = branch
+        .branch()
+        .map(|branch| branch.branch_name())
+        .unwrap_or(name);
+    (name, branch)
+}
+
+/// Setup for a worktree once its files are checked out: copying ignored files, running `direnv
+/// allow`, installing tools with `mise` or `asdf`, and running `add.commands`.
+#[derive(Debug)]
+pub struct WorktreeSetup<'a, 'b> {
+    /// The worktree to copy ignored files from.
+    pub git: &'b AppGit<'a, Utf8PathBuf>,
+    /// The worktree to set up.
+    pub destination: &'b Utf8Path,
+    /// Ignored paths to copy from `git`'s worktree to `destination`.
+    pub copy_ignored: &'b [StatusEntry],
+    /// Whether to copy symlinks in `copy_ignored`, rather than skipping them.
+    pub copy_ignored_symlinks: bool,
+}
+
+impl WorktreeSetup<'_, '_> {
+    #[instrument(level = "trace")]
+    pub fn execute(&self) -> miette::Result<()> {
+        self.copy_ignored()?;
+        self.share_submodules()?;
+        self.direnv_allow()?;
+        self.setup_tools()?;
+        self.run_commands()?;
+        Ok(())
+    }
+
     #[instrument(level = "trace")]
     fn copy_ignored(&self) -> miette::Result<()> {
         if self.copy_ignored.is_empty() {
@@ -171,7 +1121,7 @@ impl<'a> WorktreePlan<'a> {
             "Copying untracked files to {}",
             self.destination.display_path_cwd()
         );
-        for entry in &self.copy_ignored {
+        for entry in self.copy_ignored {
             let path = &entry.path;
             let from = self.git.get_current_dir().join(path);
             let to = self.destination.join(path);
@@ -180,7 +1130,13 @@ impl<'a> WorktreePlan<'a> {
                 %from, %to,
                 "Copying untracked file"
             );
-            let errors = crate::copy_dir::copy_dir(&from, &to)
+            let errors = if self.copy_ignored_symlinks {
+                crate::copy_dir::copy_dir(&from, &to)
+            } else {
+                // Test: `add_no_copy_ignored_symlinks`
+                crate::copy_dir::copy_dir_without_symlinks(&from, &to)
+            };
+            let errors = errors
                 .into_diagnostic()
                 .wrap_err_with(|| format!("Failed to copy untracked files from {from} to {to}"))?;
             if !errors.is_empty() {
@@ -194,32 +1150,125 @@ impl<'a> WorktreePlan<'a> {
     }
 
     #[instrument(level = "trace")]
-    pub fn execute(&self) -> miette::Result<()> {
-        let mut command = self.command();
-
-        // Test: `add_destination_exists`
-        if self.destination.exists() {
-            return Err(miette!(
-                "Worktree destination {} already exists",
-                self.destination.display_path_cwd()
-            ));
+    fn share_submodules(&self) -> miette::Result<()> {
+        if !self.git.config.file.add.share_submodules() {
+            // Test: `config_add_share_submodules_default`
+            return Ok(());
         }
 
-        tracing::info!("{self}");
-        tracing::debug!("{self:#?}");
+        let output = self
+            .git
+            .with_current_dir(self.destination.to_owned())
+            .command()
+            .args([
+                "config",
+                "--file",
+                ".gitmodules",
+                "--get-regexp",
+                r"^submodule\..*\.path$",
+            ])
+            .output_checked_with_utf8::<String>(|_output| Ok(()))?;
+
+        // Test: `config_add_share_submodules`
+        for path in output
+            .stdout
+            .lines()
+            .filter_map(|line| line.split_once(' ').map(|(_key, path)| path))
+        {
+            let mut command = self
+                .git
+                .with_current_dir(self.destination.to_owned())
+                .command();
+            command.args(["submodule", "update", "--init"]);
+            let reference = self.git.get_current_dir().join(path);
+            if reference.join(".git").exists() {
+                command.arg("--reference").arg(reference);
+            }
+            command.args(["--", path]);
 
-        if self.git.config.cli.dry_run {
             tracing::info!(
                 "{} {}",
                 '$'.if_supports_color(Stream::Stdout, |text| text.green()),
                 Utf8ProgramAndArgs::from(&command)
             );
+            command
+                .status_checked()
+                .into_diagnostic()
+                .wrap_err_with(|| format!("Failed to initialize submodule {path}"))?;
+        }
+
+        Ok(())
+    }
+
+    #[instrument(level = "trace")]
+    fn direnv_allow(&self) -> miette::Result<()> {
+        if !self.git.config.file.add.direnv_allow() || !self.destination.join(".envrc").exists() {
+            // Test: `config_add_direnv_allow_default`
             return Ok(());
         }
 
-        command.status_checked()?;
-        self.copy_ignored()?;
-        self.run_commands()?;
+        if which_global("direnv").is_err() {
+            tracing::debug!("`direnv` isn't installed; not running `direnv allow`");
+            return Ok(());
+        }
+
+        // Test: `config_add_direnv_allow`
+        let mut command = Command::new("direnv");
+        command.arg("allow").arg(self.destination);
+        tracing::info!(
+            "{} {}",
+            '$'.if_supports_color(Stream::Stdout, |text| text.green()),
+            Utf8ProgramAndArgs::from(&command)
+        );
+        command
+            .current_dir(self.destination)
+            .status_checked()
+            .into_diagnostic()
+            .wrap_err("Failed to run `direnv allow` in new worktree")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "trace")]
+    fn setup_tools(&self) -> miette::Result<()> {
+        if !self.git.config.file.add.setup_tools() {
+            return Ok(());
+        }
+
+        let tool_versions = self.destination.join(".tool-versions").exists();
+        let mise_toml = self.destination.join("mise.toml").exists();
+        if !tool_versions && !mise_toml {
+            return Ok(());
+        }
+
+        let (program, trust) = if which_global("mise").is_ok() {
+            // Test: `config_add_setup_tools`
+            ("mise", mise_toml)
+        } else if tool_versions && which_global("asdf").is_ok() {
+            // Test: `config_add_setup_tools_asdf`
+            ("asdf", false)
+        } else {
+            tracing::debug!("Neither `mise` nor `asdf` is installed; not installing tools");
+            return Ok(());
+        };
+
+        for subcommand in trust.then_some("trust").into_iter().chain(["install"]) {
+            let mut command = Command::new(program);
+            command.arg(subcommand);
+            tracing::info!(
+                "{} {}",
+                '$'.if_supports_color(Stream::Stdout, |text| text.green()),
+                Utf8ProgramAndArgs::from(&command)
+            );
+            command
+                .current_dir(self.destination)
+                .status_checked()
+                .into_diagnostic()
+                .wrap_err_with(|| {
+                    format!("Failed to run `{program} {subcommand}` in new worktree")
+                })?;
+        }
+
         Ok(())
     }
 
@@ -233,10 +1282,15 @@ impl<'a> WorktreePlan<'a> {
                 '$'.if_supports_color(Stream::Stdout, |text| text.green())
             );
             let status = command
-                .current_dir(&self.destination)
+                .current_dir(self.destination)
                 .status_checked()
                 .into_diagnostic();
             if let Err(err) = status {
+                if self.git.config.file.add.commands_fail_fast() {
+                    // Test: `config_add_commands_fail_fast`
+                    return Err(err.wrap_err("Command failed in new worktree"));
+                }
+                // Test: `config_add_commands_fail_fast_default`
                 tracing::error!("{err}");
             }
         }
@@ -250,6 +1304,8 @@ impl<'a> WorktreePlan<'a> {
 enum StartPoint {
     /// An existing local or remote branch. The new branch should track this branch.
     Branch(BranchRef),
+    /// A tag. The new branch shouldn't track anything.
+    Tag(Ref),
     /// A commit.
     Commitish(String),
 }
@@ -266,6 +1322,14 @@ impl Display for StartPoint {
                         .if_supports_color(Stream::Stdout, |text| text.cyan())
                 )
             }
+            StartPoint::Tag(tag) => {
+                write!(
+                    f,
+                    "tag {}",
+                    tag.name()
+                        .if_supports_color(Stream::Stdout, |text| text.cyan())
+                )
+            }
             StartPoint::Commitish(commitish) => {
                 write!(
                     f,
@@ -278,25 +1342,63 @@ impl Display for StartPoint {
 }
 
 impl StartPoint {
-    pub fn new(git: &AppGit<'_, Utf8PathBuf>, commitish: Option<&str>) -> miette::Result<Self> {
+    pub fn new(
+        git: &AppGit<'_, Utf8PathBuf>,
+        args: &AddArgs,
+        commitish: Option<&str>,
+    ) -> miette::Result<Self> {
         match commitish {
             Some(commitish) => match git.branch().local_or_remote(commitish)? {
                 Some(branch) => Ok(Self::Branch(branch)),
-                None => Ok(Self::Commitish(commitish.to_owned())),
+                // Unresolvable commits are left for `git worktree add` to report.
+                None => match git.refs().rev_parse_symbolic_full_name(commitish)? {
+                    Some(ref_name) if ref_name.is_tag() => Ok(Self::Tag(ref_name)),
+                    _ => Ok(Self::Commitish(commitish.to_owned())),
+                },
             },
-            None => Ok(Self::preferred(git)?),
+            None => Ok(Self::preferred(git, args)?),
         }
     }
 
-    pub fn preferred(git: &AppGit<'_, Utf8PathBuf>) -> miette::Result<Self> {
-        Ok(Self::Branch(git.branch().preferred()?.ok_or_else(
-            || miette!("No default branch found; pass a COMMITISH to start the new worktree at"),
-        )?))
+    pub fn preferred(git: &AppGit<'_, Utf8PathBuf>, args: &AddArgs) -> miette::Result<Self> {
+        if args
+            .inherit_remote()
+            .unwrap_or_else(|| git.config.file.add.inherit_remote())
+        {
+            if let Some(remote) = Self::current_upstream_remote(git)? {
+                // Tests:
+                // - `add_inherit_remote`
+                // - `config_add_inherit_remote`
+                return Ok(Self::Branch(
+                    git.remote().default_branch_cached(&remote)?.into(),
+                ));
+            }
+            tracing::debug!("Current branch has no upstream remote; using the default branch");
+        }
+
+        Ok(Self::Branch(git.main_branch()?.ok_or_else(|| {
+            miette!("No default branch found; pass a COMMITISH to start the new worktree at")
+        })?))
+    }
+
+    /// The remote the current branch's upstream is on, if any.
+    fn current_upstream_remote(git: &AppGit<'_, Utf8PathBuf>) -> miette::Result<Option<String>> {
+        let current = match git.branch().current()? {
+            Some(current) => current,
+            None => {
+                return Ok(None);
+            }
+        };
+        Ok(match git.branch().upstream(current.branch_name())? {
+            Some(BranchRef::Remote(upstream)) => Some(upstream.remote().to_owned()),
+            _ => None,
+        })
     }
 
     pub fn commitish(&self) -> &str {
         match self {
             Self::Branch(start) => start.qualified_branch_name(),
+            Self::Tag(tag) => tag.name(),
             Self::Commitish(commitish) => commitish,
         }
     }
@@ -326,12 +1428,23 @@ enum BranchStartPointPlan {
 }
 
 impl BranchStartPointPlan {
+    /// The branch which will be checked out in the new worktree, if any.
+    fn branch(&self) -> Option<&LocalBranchRef> {
+        match self {
+            BranchStartPointPlan::New { branch, .. } | BranchStartPointPlan::Existing(branch) => {
+                Some(branch)
+            }
+            BranchStartPointPlan::Detach(_) => None,
+        }
+    }
+
     /// Create a branch and start-point plan from the given arguments.
     ///
     /// There's a lot of permutations to this functionality, so here's a big table!
     ///
     /// In general, for a fragment `NAME`, we perform the following logic:
-    /// - If `NAME` is the name of a local branch, that branch is checked out.
+    /// - If `NAME` is the name of a local branch, that branch is checked out, even if a remote
+    ///   branch with the same name exists (a warning is logged if they point to different commits).
     /// - If `NAME` is the name of a remote branch, a new local branch with the same name is
     ///   created to track the remote branch.
     /// - Otherwise, a new branch is created named `NAME` at the default starting point.
@@ -356,42 +1469,63 @@ impl BranchStartPointPlan {
     ///
     /// This was very annoying to iron out, but hopefully it does what you want more of the time
     /// than `git-worktree(1)`.
-    pub fn new(git: &AppGit<'_, Utf8PathBuf>, args: &AddArgs) -> miette::Result<Self> {
+    pub fn new(
+        git: &AppGit<'_, Utf8PathBuf>,
+        args: &AddArgs,
+        commitish: Option<&str>,
+    ) -> miette::Result<Self> {
         match (&args.inner.branch, &args.inner.force_branch) {
             (Some(_), Some(_)) => unreachable!(),
             // `add --branch BRANCH [NAME_OR_PATH [COMMITISH]]`
             (Some(branch), None) => Ok(Self::New {
                 force: false,
-                branch: LocalBranchRef::from(branch),
-                start: StartPoint::new(git, args.commitish.as_deref())?,
+                branch: new_branch_name(git, branch),
+                start: StartPoint::new(git, args, commitish)?,
             }),
             // `add --force-branch BRANCH [NAME_OR_PATH [COMMITISH]]`
             (None, Some(force_branch)) => Ok(Self::New {
                 force: true,
-                branch: LocalBranchRef::from(force_branch),
-                start: StartPoint::new(git, args.commitish.as_deref())?,
+                branch: new_branch_name(git, force_branch),
+                start: StartPoint::new(git, args, commitish)?,
             }),
             (None, None) => {
-                if args.inner.detach {
+                if let Some(issue) = args.inner.issue {
+                    // `add --issue NUMBER [NAME_OR_PATH [COMMITISH]]`
+                    Ok(Self::New {
+                        force: false,
+                        branch: new_branch_name(git, &issue_branch_name(git, issue)),
+                        start: StartPoint::new(git, args, commitish)?,
+                    })
+                } else if args.inner.detach {
                     // `add --detach NAME_OR_PATH [COMMITISH]`
-                    Self::new_detached(git, args.commitish.as_deref())
+                    Self::new_detached(git, args, commitish)
                 } else {
                     let name_or_path = args
                         .inner
                         .name_or_path
                         .as_deref()
                         .expect("If `--branch` is not given, `NAME_OR_PATH` must be given");
-                    // TODO: It would be nice if there was a set of regexes for the
-                    // branch name itself, as well.
                     let dirname = final_component(name_or_path);
 
-                    match &args.commitish {
+                    if args.track_remote_only {
+                        // Test: `add_track_remote_only`
+                        let branch = commitish.unwrap_or(dirname);
+                        if git.remote().for_branch(branch)?.is_none() {
+                            return Err(miette!(
+                                "Branch {} wasn't found on any remote; not creating a new local \
+                                 branch because `--track-remote-only` was given",
+                                branch.if_supports_color(Stream::Stdout, |text| text.cyan())
+                            ));
+                        }
+                    }
+
+                    match commitish {
                         Some(commitish) => match Self::from_commitish(git, commitish)? {
                             // `add NAME_OR_PATH LOCAL_BRANCH`
                             // `add NAME_OR_PATH REMOTE_BRANCH`
                             Some(plan) => Ok(plan),
                             // `add NAME_OR_PATH COMMITISH`
-                            None => Self::new_branch_at(git, false, dirname, Some(commitish)),
+                            None => Self::new_branch_at(git, args, false, dirname, Some(commitish)),
                         },
 
                         // `add NAME_OR_PATH`
@@ -400,7 +1534,7 @@ impl BranchStartPointPlan {
                             // `add ../puppy/REMOTE_BRANCH`
                             Some(plan) => Ok(plan),
                             // `add ../puppy/SOMETHING_ELSE`
-                            None => Self::new_branch_at(git, false, dirname, None),
+                            None => Self::new_branch_at(git, args, false, dirname, None),
                         },
                     }
                 }
@@ -410,22 +1544,33 @@ impl BranchStartPointPlan {
 
     fn new_branch_at(
         git: &AppGit<'_, Utf8PathBuf>,
+        args: &AddArgs,
         force: bool,
         branch: &str,
         commitish: Option<&str>,
     ) -> miette::Result<Self> {
         Ok(Self::New {
             force,
-            branch: LocalBranchRef::new(branch.to_owned()),
-            start: StartPoint::new(git, commitish)?,
+            branch: new_branch_name(git, branch),
+            start: StartPoint::new(git, args, commitish)?,
         })
     }
 
     fn new_detached(
         git: &AppGit<'_, Utf8PathBuf>,
+        args: &AddArgs,
         commitish: Option<&str>,
     ) -> miette::Result<Self> {
-        Ok(Self::Detach(StartPoint::new(git, commitish)?))
+        let commitish = match (commitish, args.inner.name_or_path.as_deref()) {
+            (None, Some(name)) if !name.contains('/') && named_ref(git, name)?.is_some() => {
+                // `add --detach REF`
+                //
+                // Test: `add_detached_tag`
+                Some(name)
+            }
+            (commitish, _) => commitish,
+        };
+        Ok(Self::Detach(StartPoint::new(git, args, commitish)?))
     }
 
     fn from_commitish(
@@ -478,7 +1623,7 @@ impl Display for BranchStartPointPlan {
                     StartPoint::Branch(_) => {
                         write!(f, " tracking {start}")
                     }
-                    StartPoint::Commitish(_) => {
+                    StartPoint::Tag(_) | StartPoint::Commitish(_) => {
                         write!(f, " starting at {start}")
                     }
                 }
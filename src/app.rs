@@ -1,10 +1,13 @@
 use calm_io::stdout;
+use camino::Utf8Path;
 use camino::Utf8PathBuf;
 use clap::CommandFactory;
+use clap::ValueEnum;
+use clap_complete::Shell;
 use miette::miette;
+use miette::Context;
 use miette::IntoDiagnostic;
 
-use crate::add::WorktreePlan;
 use crate::app_git::AppGit;
 use crate::cli;
 use crate::cli::ConfigCommand;
@@ -12,8 +15,11 @@ use crate::cli::ConfigInitArgs;
 use crate::config::Config;
 use crate::convert::ConvertPlan;
 use crate::convert::ConvertPlanOpts;
+use crate::exit_code::ErrorCode;
 use crate::fs;
 use crate::git::Git;
+use crate::git::GitLike;
+use crate::PathDisplay;
 
 pub struct App {
     config: Config,
@@ -24,24 +30,66 @@ impl App {
         Self { config }
     }
 
+    /// Get a [`Git`] for the current directory, which must be in a Git repository.
     pub fn git(&self) -> miette::Result<AppGit<'_, Utf8PathBuf>> {
+        let git = self.git_anywhere()?;
+        match git.path().repository_dir()? {
+            // Test: `list_from_container_git_dir_name`
+            Some(repository_dir) => Ok(git.with_current_dir(repository_dir)),
+            None => {
+                // Test: `not_in_repository`
+                Err(ErrorCode::NotARepository.error(format!(
+                    "Not inside a Git repository: {}\n\
+                     Use `git prole clone` to clone a repository or `git init` to create one",
+                    git.get_current_dir().display_path_cwd()
+                )))
+            }
+        }
+    }
+
+    /// Get a [`Git`] for the current directory, which doesn't need to be in a Git repository.
+    pub fn git_anywhere(&self) -> miette::Result<AppGit<'_, Utf8PathBuf>> {
         Ok(Git::from_current_dir()?.with_config(&self.config))
     }
 
+    /// Change to the `--directory`, if it's given.
+    ///
+    /// Like `git -C`, this changes the process's working directory, so relative paths in other
+    /// arguments are resolved from the `--directory`.
+    fn change_directory(&self) -> miette::Result<()> {
+        if let Some(directory) = &self.config.cli.directory {
+            if !directory.is_dir() {
+                // Test: `directory_missing`
+                return Err(miette!(
+                    "`--directory` is not a directory: {}",
+                    directory.display_path_cwd()
+                ));
+            }
+            // Tests:
+            // - `directory`
+            // - `directory_relative_path`
+            std::env::set_current_dir(directory)
+                .into_diagnostic()
+                .wrap_err_with(|| format!("Failed to change directory to {directory}"))?;
+        }
+        Ok(())
+    }
+
     pub fn run(self) -> miette::Result<()> {
+        self.change_directory()?;
+
         match &self.config.cli.command {
-            cli::Command::Completions { shell } => {
-                let mut clap_command = cli::Cli::command();
-                clap_complete::generate(
-                    *shell,
-                    &mut clap_command,
-                    "git-prole",
-                    &mut std::io::stdout(),
-                );
+            cli::Command::Completions { shell, all, output } => {
+                Self::completions(*shell, *all, output.as_deref())?
             }
             #[cfg(feature = "clap_mangen")]
-            cli::Command::Manpages { out_dir } => {
+            cli::Command::Manpages { out_dir, output } => {
                 use miette::Context;
+                let out_dir = output
+                    .as_deref()
+                    .or(out_dir.as_deref())
+                    .expect("Either `OUT_DIR` or `--output` is required");
+                fs::create_dir_all(out_dir)?;
                 let clap_command = cli::Cli::command();
                 clap_mangen::generate_to(clap_command, out_dir)
                     .into_diagnostic()
@@ -52,17 +100,80 @@ impl App {
                 ConvertPlanOpts {
                     default_branch: args.default_branch.clone(),
                     destination: args.destination.clone(),
+                    container: args.container.clone(),
+                    no_fetch: args.no_fetch,
+                    default_worktree_name_from_dir: args.default_worktree_name_from_dir,
+                    skip_default_worktree: args.skip_default_worktree,
+                    git_dir_name: args.git_dir_name.clone(),
+                    no_hooks: args.no_hooks,
+                    bare: !args.no_bare && self.config.file.convert.bare(),
+                    allow_outside: args.allow_outside,
+                    yes: args.yes,
                 },
             )?
             .execute()?,
-            cli::Command::Clone(args) => crate::clone::clone(self.git()?, args.to_owned())?,
-            cli::Command::Add(args) => WorktreePlan::new(self.git()?, args)?.execute()?,
+            cli::Command::Clone(args) => {
+                crate::clone::clone(self.git_anywhere()?, args.to_owned())?
+            }
+            cli::Command::Add(args) => crate::add::add(self.git()?, args)?,
+            cli::Command::Remove(args) => crate::remove::remove(self.git()?, args)?,
+            cli::Command::Prune(args) => crate::prune::prune(self.git()?, args)?,
+            cli::Command::List(args) => crate::list::list(self.git()?, args)?,
+            cli::Command::Cd(args) => crate::cd::cd(self.git()?, args)?,
+            cli::Command::Whereami(args) => crate::whereami::whereami(self.git()?, args)?,
+            cli::Command::Verify(args) => crate::verify::verify(self.git()?, args)?,
+            cli::Command::Populate(args) => crate::populate::populate(self.git()?, args)?,
             cli::Command::Config(ConfigCommand::Init(args)) => self.config_init(args.to_owned())?,
         }
 
         Ok(())
     }
 
+    fn completions(
+        shell: Option<Shell>,
+        all: bool,
+        output: Option<&Utf8Path>,
+    ) -> miette::Result<()> {
+        let mut clap_command = cli::Cli::command();
+
+        if all {
+            // Test: `completions_all`
+            let out_dir = output.expect("`--all` requires `--output`");
+            fs::create_dir_all(out_dir)?;
+            for shell in Shell::value_variants() {
+                let path =
+                    clap_complete::generate_to(*shell, &mut clap_command, "git-prole", out_dir)
+                        .into_diagnostic()
+                        .wrap_err_with(|| format!("Failed to generate {shell} completions"))?;
+                tracing::debug!(%shell, path = %path.display(), "Wrote completions");
+            }
+            return Ok(());
+        }
+
+        let shell = shell.expect("`SHELL` is required unless `--all` is given");
+        match output {
+            Some(output) => {
+                // Test: `completions_output`
+                let mut completions = Vec::new();
+                clap_complete::generate(shell, &mut clap_command, "git-prole", &mut completions);
+                if let Some(parent) = output.parent().filter(|parent| !parent.as_str().is_empty()) {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(output, completions)?;
+            }
+            None => {
+                clap_complete::generate(
+                    shell,
+                    &mut clap_command,
+                    "git-prole",
+                    &mut std::io::stdout(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     fn config_init(&self, args: ConfigInitArgs) -> miette::Result<()> {
         let path = match &args.output {
             Some(path) => {
@@ -77,6 +188,9 @@ impl App {
         };
 
         if path.exists() {
+            if args.merge {
+                return Self::config_merge(path);
+            }
             return Err(miette!("Default configuration file already exists: {path}"));
         }
 
@@ -93,4 +207,30 @@ impl App {
 
         Ok(())
     }
+
+    fn config_merge(path: &Utf8Path) -> miette::Result<()> {
+        let contents = fs::read_to_string(path)?;
+
+        // Test: `config_init_merge`
+        let Some(missing) = Config::missing_defaults(&contents)
+            .wrap_err_with(|| format!("Failed to merge default settings into {path}"))?
+        else {
+            tracing::info!(%path, "Configuration file already contains every setting");
+            return Ok(());
+        };
+
+        tracing::info!(
+            %path,
+            "Adding missing settings to configuration file"
+        );
+
+        let mut contents = contents;
+        if !contents.is_empty() && !contents.ends_with('\n') {
+            contents.push('\n');
+        }
+        contents.push_str(&missing);
+        fs::write(path, contents)?;
+
+        Ok(())
+    }
 }
@@ -3,10 +3,15 @@ use std::ops::Deref;
 use std::ops::DerefMut;
 
 use camino::Utf8Path;
+use tracing::instrument;
 
 use crate::config::Config;
+use crate::fs;
+use crate::git::BranchRef;
 use crate::git::Git;
 use crate::git::GitLike;
+use crate::git::LocalBranchRef;
+use crate::git::RemoteBranchRef;
 
 /// A [`Git`] with borrowed [`Config`].
 #[derive(Clone)]
@@ -76,10 +81,98 @@ impl<'a, C> AppGit<'a, C>
 where
     C: AsRef<Utf8Path>,
 {
+    /// The repository-local Git config setting which names the default branch.
+    pub const DEFAULT_BRANCH_SETTING: &'static str = "prole.defaultBranch";
+
+    /// A file in the worktree container which names the default branch.
+    pub const DEFAULT_BRANCH_FILE: &'static str = ".git-prole-default-branch";
+
     pub fn with_current_dir<C2>(&self, path: C2) -> AppGit<'a, C2> {
         AppGit {
             git: self.git.with_current_dir(path),
             config: self.config,
         }
     }
+
+    /// Get the repository's main (default) branch.
+    ///
+    /// This tries, in order:
+    /// 1. The `remote_branch_names` configured for the user's preferred remote.
+    /// 2. The default branch of the user's preferred remote.
+    /// 3. The `prole.defaultBranch` Git setting.
+    /// 4. Git's `init.defaultBranch` setting.
+    /// 5. The `branch_names` configuration setting.
+    /// 6. The contents of a `.git-prole-default-branch` file in the worktree container.
+    ///
+    /// Branches named by the settings or the file are only used if they exist locally or on a
+    /// remote. If the remote's default branch can't be determined (e.g. when offline), the later
+    /// steps are tried instead.
+    #[instrument(level = "trace")]
+    pub fn main_branch(&self) -> miette::Result<Option<BranchRef>> {
+        if let Some(default_remote) = self.remote().preferred()? {
+            for name in self.config.file.remote_branch_names(&default_remote) {
+                let branch = RemoteBranchRef::new(&default_remote, name);
+                if self.refs().parse(&format!("{branch:#}"))?.is_some() {
+                    // Test: `config_remote_branch_names`
+                    tracing::debug!(%branch, "Found default branch from `remote_branch_names`");
+                    return Ok(Some(branch.into()));
+                }
+            }
+
+            match self.remote().default_branch_cached(&default_remote) {
+                Ok(branch) => {
+                    return Ok(Some(branch.into()));
+                }
+                Err(err) => {
+                    // E.g. when offline and `origin/HEAD` isn't set.
+                    //
+                    // Test: `main_branch_remote_unreachable`
+                    tracing::debug!(
+                        %default_remote,
+                        "Failed to get default branch for remote:\n{err:?}"
+                    );
+                }
+            }
+        }
+
+        for setting in [Self::DEFAULT_BRANCH_SETTING, "init.defaultBranch"] {
+            if let Some(name) = self.config().get(setting)? {
+                if let Some(branch) = self.existing_branch(&name)? {
+                    // Tests:
+                    // - `main_branch_git_config`
+                    // - `main_branch_init_default_branch`
+                    tracing::debug!(%branch, "Found default branch from `{setting}`");
+                    return Ok(Some(branch));
+                }
+            }
+        }
+
+        for name in self.config.file.branch_names() {
+            if let Some(branch) = self.existing_branch(&name)? {
+                return Ok(Some(branch));
+            }
+        }
+
+        let marker = self.worktree().container()?.join(Self::DEFAULT_BRANCH_FILE);
+        if marker.exists() {
+            let name = fs::read_to_string(&marker)?;
+            if let Some(branch) = self.existing_branch(name.trim())? {
+                // Test: `main_branch_marker_file`
+                tracing::debug!(%branch, %marker, "Found default branch from marker file");
+                return Ok(Some(branch));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Find a local branch with the given name, or a branch with that name on a remote.
+    fn existing_branch(&self, name: &str) -> miette::Result<Option<BranchRef>> {
+        let local = LocalBranchRef::new(name.to_owned());
+        if self.branch().exists_local(local.branch_name())? {
+            Ok(Some(local.into()))
+        } else {
+            Ok(self.remote().for_branch(name)?.map(BranchRef::from))
+        }
+    }
 }
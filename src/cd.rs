@@ -0,0 +1,27 @@
+use calm_io::stdoutln;
+use camino::Utf8Path;
+use miette::IntoDiagnostic;
+
+use crate::app_git::AppGit;
+use crate::cli::CdArgs;
+use crate::git::GitLike;
+
+pub fn cd<C>(git: AppGit<'_, C>, args: &CdArgs) -> miette::Result<()>
+where
+    C: AsRef<Utf8Path>,
+{
+    let path = match &args.name_or_path {
+        Some(name_or_path) => {
+            // Tests:
+            // - `cd_branch_with_slash`
+            // - `cd_prefix`
+            git.worktree().list()?.resolve(name_or_path)?.path.clone()
+        }
+        // Test: `cd_default`
+        None => git.worktree().find_some()?,
+    };
+
+    // Test: `cd`
+    stdoutln!("{path}").into_diagnostic()?;
+    Ok(())
+}
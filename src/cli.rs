@@ -1,8 +1,11 @@
 use camino::Utf8PathBuf;
+use clap::ArgAction;
 use clap::Args;
 use clap::Parser;
 use clap::Subcommand;
 
+use crate::git::UrlBranch;
+
 /// A `git-worktree(1)` manager.
 #[derive(Debug, Clone, Parser)]
 #[command(version, author, about)]
@@ -11,9 +14,25 @@ pub struct Cli {
     /// Log filter directives, of the form `target[span{field=value}]=level`, where all components
     /// except the level are optional.
     ///
-    /// Try `debug` or `trace`.
-    #[arg(long, default_value = "info", env = "GIT_PROLE_LOG", global = true)]
-    pub log: String,
+    /// Try `debug` or `trace`. Defaults to `info`, or as set by `--quiet` or `--verbose`.
+    #[arg(long, env = "GIT_PROLE_LOG", global = true)]
+    pub log: Option<String>,
+
+    /// Only log warnings and errors, and pass `--quiet` to `git clone`.
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Log more details, and pass `--progress` to `git clone`.
+    ///
+    /// Give twice to log everything.
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// Disable colored output, including in logs.
+    ///
+    /// Without this, colors are used when the output is a terminal.
+    #[arg(long, global = true)]
+    pub no_color: bool,
 
     /// If set, do not perform any actions, and instead only construct and print a plan.
     #[arg(long, visible_alias = "dry", default_value = "false", global = true)]
@@ -24,6 +43,12 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub config: Option<Utf8PathBuf>,
 
+    /// Run as if `git prole` was started in this directory instead of the current directory.
+    ///
+    /// Like `git -C`, this must be given before the subcommand (`add -C` creates a branch).
+    #[arg(short = 'C', long, value_name = "PATH")]
+    pub directory: Option<Utf8PathBuf>,
+
     #[command(subcommand)]
     pub command: Command,
 }
@@ -33,18 +58,68 @@ impl Cli {
     #[cfg(test)]
     pub fn test_stub() -> Self {
         Self {
-            log: "info".to_owned(),
+            log: None,
+            quiet: false,
+            verbose: 0,
+            no_color: false,
             dry_run: false,
             config: None,
+            directory: None,
             command: Command::Convert(ConvertArgs {
                 default_branch: None,
+                no_fetch: false,
+                default_worktree_name_from_dir: false,
+                skip_default_worktree: false,
+                git_dir_name: None,
+                container: None,
+                no_hooks: false,
+                no_bare: false,
+                allow_outside: false,
+                yes: false,
                 destination: None,
             }),
         }
     }
+
+    /// The log filter directives to use, from `--log` or `--quiet`/`--verbose`.
+    pub fn log_filter(&self) -> &str {
+        match &self.log {
+            Some(log) => log,
+            None => match self.verbosity() {
+                Verbosity::Quiet => "warn",
+                Verbosity::Normal => "info",
+                Verbosity::Verbose if self.verbose == 1 => "debug",
+                Verbosity::Verbose => "trace",
+            },
+        }
+    }
+
+    /// How much output to show, from `--quiet` and `--verbose`.
+    pub fn verbosity(&self) -> Verbosity {
+        if self.quiet {
+            Verbosity::Quiet
+        } else if self.verbose > 0 {
+            Verbosity::Verbose
+        } else {
+            Verbosity::Normal
+        }
+    }
+}
+
+/// How much output to show, as set by `--quiet` and `--verbose`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verbosity {
+    /// `--quiet`.
+    Quiet,
+    /// Neither `--quiet` nor `--verbose`.
+    Normal,
+    /// `--verbose`, given at least once.
+    Verbose,
 }
 
 #[allow(rustdoc::bare_urls)]
+// This is only constructed once, when arguments are parsed.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Subcommand)]
 pub enum Command {
     /// Convert a repository into a worktree checkout.
@@ -81,6 +156,51 @@ pub enum Command {
     /// By default, untracked files are copied to the new worktree.
     Add(AddArgs),
 
+    /// Remove a worktree, and optionally its branch.
+    ///
+    /// This refuses to remove the main worktree, or the worktree you're in unless `--force` is
+    /// given.
+    Remove(RemoveArgs),
+
+    /// Prune the administrative files of worktrees which no longer exist.
+    ///
+    /// This lists the prunable worktrees and why they're prunable, and then runs
+    /// `git worktree prune`.
+    Prune(PruneArgs),
+
+    /// List worktrees, with their branches, upstreams, and whether they have uncommitted changes.
+    ///
+    /// Worktrees are printed as an aligned table, with a `*` marking worktrees with uncommitted
+    /// changes. The main worktree is listed first, then the rest in order of their paths.
+    List(ListArgs),
+
+    /// Print the path to a worktree, for changing directories.
+    ///
+    /// Only the path is printed, so this can be used from a shell function like
+    /// `gcd() { cd "$(git prole cd "$@")" || return; }`.
+    Cd(CdArgs),
+
+    /// Print the current worktree, worktree container, Git directory, and branch.
+    ///
+    /// Each field is printed on its own line, prefixed by its name. The worktree is omitted if
+    /// not in a worktree, and the branch is omitted if `HEAD` is detached.
+    Whereami(WhereamiArgs),
+
+    /// Check that every worktree's links to the common `.git` directory are valid.
+    ///
+    /// Each worktree's `.git` file must point to a directory in `.git/worktrees`, and that
+    /// directory's `gitdir` file must point back to the worktree. Broken links are reported but
+    /// not fixed, unless `--fix` is given.
+    ///
+    /// Exits with a non-zero status if any links are broken.
+    Verify(VerifyArgs),
+
+    /// Check out the files in a worktree created with `git prole add --no-checkout`.
+    ///
+    /// After checking out files, ignored files are copied from the current worktree and
+    /// `add.commands` are run, as they would be for `git prole add`.
+    Populate(PopulateArgs),
+
     /// Initialize the configuration file.
     #[command(subcommand)]
     Config(ConfigCommand),
@@ -88,14 +208,33 @@ pub enum Command {
     /// Generate shell completions.
     Completions {
         /// Shell to generate completions for.
-        shell: clap_complete::shells::Shell,
+        #[arg(required_unless_present = "all")]
+        shell: Option<clap_complete::shells::Shell>,
+
+        /// Generate completions for all supported shells.
+        ///
+        /// The completions are written to files in the `--output` directory.
+        #[arg(long, conflicts_with = "shell", requires = "output")]
+        all: bool,
+
+        /// File to write completions to, instead of stdout.
+        ///
+        /// With `--all`, this is the directory to write completions to. Parent directories are
+        /// created if needed.
+        #[arg(long, short)]
+        output: Option<camino::Utf8PathBuf>,
     },
 
     /// Generate man pages.
     #[cfg(feature = "clap_mangen")]
     Manpages {
         /// Directory to write man pages to.
-        out_dir: camino::Utf8PathBuf,
+        #[arg(required_unless_present = "output", conflicts_with = "output")]
+        out_dir: Option<camino::Utf8PathBuf>,
+
+        /// Directory to write man pages to. Parent directories are created if needed.
+        #[arg(long, short, value_name = "DIR")]
+        output: Option<camino::Utf8PathBuf>,
     },
 }
 
@@ -105,7 +244,67 @@ pub struct ConvertArgs {
     #[arg(long)]
     pub default_branch: Option<String>,
 
-    /// The directory to place the worktrees into.
+    /// Don't fetch the default branch if it only exists on a remote.
+    ///
+    /// If the default branch has a remote-tracking branch, a local branch is created from it.
+    /// Otherwise, conversion fails rather than accessing the network.
+    #[arg(long)]
+    pub no_fetch: bool,
+
+    /// Name the default branch's worktree after the original repository directory, rather than
+    /// after the branch.
+    ///
+    /// For example, converting `~/puppy` creates a worktree in `~/puppy/puppy` instead of
+    /// `~/puppy/main`.
+    #[arg(long)]
+    pub default_worktree_name_from_dir: bool,
+
+    /// Don't create a worktree for the default branch if it isn't already checked out.
+    ///
+    /// The repository is still made bare and the existing worktrees are still moved.
+    #[arg(long)]
+    pub skip_default_worktree: bool,
+
+    /// The name of the bare Git directory in the worktree container, like `bare` or
+    /// `my-repo.git`.
+    ///
+    /// Defaults to the `convert.git_dir_name` setting, which is `.git` by default.
+    #[arg(long, value_name = "NAME", conflicts_with = "no_bare")]
+    pub git_dir_name: Option<String>,
+
+    /// A directory to place the worktrees in, separately from the Git directory.
+    ///
+    /// By default, the worktrees are placed next to the Git directory in the `DESTINATION`. For
+    /// example, `git prole convert --git-dir-name puppy.git --container ~/work/puppy ~/bare`
+    /// puts the Git directory in `~/bare/puppy.git` and the worktrees in `~/work/puppy`.
+    #[arg(long, value_name = "PATH")]
+    pub container: Option<Utf8PathBuf>,
+
+    /// Don't run the `convert.commands` after converting.
+    #[arg(long)]
+    pub no_hooks: bool,
+
+    /// Don't make the repository bare.
+    ///
+    /// Instead, the main worktree keeps the Git directory and is moved next to the other
+    /// worktrees, like `~/puppy/main/.git`. The main worktree is usually the default branch's
+    /// worktree. Repositories which are already bare stay bare.
+    ///
+    /// Defaults to the inverse of the `convert.bare` setting.
+    #[arg(long)]
+    pub no_bare: bool,
+
+    /// Convert the repository even if it's outside of the `safe_roots` setting's directories.
+    #[arg(long)]
+    pub allow_outside: bool,
+
+    /// Don't ask for confirmation before converting.
+    ///
+    /// This is required if stdin isn't a terminal. Nothing is asked with `--dry-run`.
+    #[arg(long, short)]
+    pub yes: bool,
+
+    /// The directory to place the Git directory and worktrees into.
     #[arg()]
     pub destination: Option<Utf8PathBuf>,
 }
@@ -122,6 +321,13 @@ pub struct CloneArgs {
     #[arg()]
     pub directory: Option<Utf8PathBuf>,
 
+    /// Set `checkout.defaultRemote` to this remote in the new repository.
+    ///
+    /// This makes checking out branches which exist on several remotes unambiguous, e.g. in fork
+    /// workflows. Defaults to the `clone.default_remote` setting.
+    #[arg(long, value_name = "REMOTE")]
+    pub default_remote: Option<String>,
+
     /// Extra arguments to forward to `git clone`.
     #[arg(last = true)]
     pub clone_args: Vec<String>,
@@ -132,6 +338,115 @@ pub struct AddArgs {
     #[command(flatten)]
     pub inner: AddArgsInner,
 
+    /// The new worktree's directory name.
+    ///
+    /// The worktree is placed in the same directory as the other worktrees, and the branch name
+    /// is unaffected. Use this to give a worktree a short name when creating a branch with a long
+    /// name, e.g. `--branch feature/long-thing --dir lt`.
+    #[arg(long, value_name = "NAME", conflicts_with = "name_or_path")]
+    pub dir: Option<String>,
+
+    /// A description for the new branch.
+    ///
+    /// This is stored in the `branch.<name>.description` Git config setting, which is used by
+    /// `git branch --edit-description` and other tools. Can only be used when a new branch is
+    /// created.
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// Open the new worktree in a new `tmux` window.
+    ///
+    /// The window is named according to the `add.tmux_window_name` setting. If `git prole` isn't
+    /// running inside `tmux`, a warning is logged and no window is opened.
+    #[arg(long)]
+    pub tmux: bool,
+
+    /// Copy symlinks when copying ignored files to the new worktree.
+    ///
+    /// Defaults to the `add.copy_ignored_symlinks` setting.
+    #[arg(long, overrides_with = "no_copy_ignored_symlinks")]
+    copy_ignored_symlinks: bool,
+
+    /// Don't copy symlinks when copying ignored files to the new worktree.
+    ///
+    /// Regular files are still copied.
+    #[arg(long, overrides_with = "copy_ignored_symlinks")]
+    no_copy_ignored_symlinks: bool,
+
+    /// Link each worktree to its siblings with symlinks in a `.worktrees/` directory.
+    ///
+    /// After the new worktree is created, every worktree gets a `.worktrees/PATH` symlink to each
+    /// of the other worktrees, where `PATH` is the worktree's path in the container (e.g.
+    /// `.worktrees/feature/x`). The `.worktrees/` directories ignore themselves, so they don't show
+    /// up in `git status`. Defaults to the `add.link_siblings` setting.
+    #[arg(long, overrides_with = "no_link_worktrees")]
+    link_worktrees: bool,
+
+    /// Don't link each worktree to its siblings, even if the `add.link_siblings` setting is
+    /// enabled.
+    #[arg(long, overrides_with = "link_worktrees")]
+    no_link_worktrees: bool,
+
+    /// Create the worktree without checking out any files.
+    ///
+    /// Ignored files aren't copied and `add.commands` aren't run until the worktree is checked
+    /// out with `git prole populate`.
+    #[arg(long)]
+    pub no_checkout: bool,
+
+    /// Fail if the new worktree has modified files after it's set up.
+    ///
+    /// This is checked after ignored files are copied and `add.commands` are run, so that CI can
+    /// assert that worktrees start out clean.
+    #[arg(long, conflicts_with = "no_checkout")]
+    pub require_clean: bool,
+
+    /// Use the current worktree's sparse-checkout patterns in the new worktree.
+    ///
+    /// Cone-mode and non-cone-mode patterns are both copied as-is. If the current worktree
+    /// doesn't use sparse-checkout, a warning is logged and the new worktree is fully checked
+    /// out.
+    #[arg(long, conflicts_with = "no_checkout")]
+    pub inherit_sparse: bool,
+
+    /// Make an empty commit on the new branch, e.g. to open a pull request before there are any
+    /// changes.
+    ///
+    /// The commit is made after the worktree is set up, so Git hooks (including those from
+    /// `--hooks-dir`) run as usual; use `--no-verify` to skip them.
+    #[arg(long, conflicts_with_all = ["detach", "no_checkout"])]
+    pub empty: bool,
+
+    /// Skip Git hooks when making the commit for `--empty`.
+    #[arg(long, visible_alias = "no-hooks", requires = "empty")]
+    pub no_verify: bool,
+
+    /// Set `core.hooksPath` for the new worktree, so that Git runs hooks from this directory.
+    ///
+    /// Relative paths are resolved relative to the new worktree. Defaults to the
+    /// `add.hooks_dir` setting.
+    #[arg(long, value_name = "PATH")]
+    pub hooks_dir: Option<Utf8PathBuf>,
+
+    /// Add the new worktree to the `folders` in a VS Code `.code-workspace` file.
+    ///
+    /// The file is created if it doesn't exist. Defaults to the `add.code_workspace` setting.
+    #[arg(long, value_name = "PATH")]
+    pub add_to_workspace: Option<Utf8PathBuf>,
+
+    /// Lock the new worktree, so that it isn't pruned or removed.
+    ///
+    /// This is useful for worktrees on removable drives. The lock reason is generated from the
+    /// `add.lock_reason_template` setting unless `--lock-reason` is given.
+    #[arg(long)]
+    pub lock: bool,
+
+    /// Lock the new worktree with the given reason.
+    ///
+    /// Implies `--lock`.
+    #[arg(long, value_name = "REASON")]
+    pub lock_reason: Option<String>,
+
     /// The commit to check out in the new worktree.
     ///
     /// If this is the name of a unique remote branch, then a local branch with the same name will
@@ -139,11 +454,131 @@ pub struct AddArgs {
     #[arg()]
     pub commitish: Option<String>,
 
+    /// More worktree names to create, with `--multiple`.
+    #[arg(requires = "multiple", value_name = "NAME")]
+    pub more_names: Vec<String>,
+
+    /// Treat every positional argument as a worktree name, and create a worktree for each.
+    ///
+    /// For example, `git prole add --multiple puppy doggy softy` creates three worktrees, as if
+    /// `git prole add` was run once for each name. (Without `--multiple`, the second argument is
+    /// the `COMMITISH` to start the new worktree at.)
+    #[arg(
+        long,
+        requires = "name_or_path",
+        conflicts_with_all = ["branch", "force_branch", "issue", "dir", "from_head_of", "pick_base", "fetch"],
+    )]
+    pub multiple: bool,
+
+    /// With `--multiple`, keep creating worktrees after one fails, and report the failures at the
+    /// end.
+    ///
+    /// By default, `git prole add --multiple` stops at the first worktree it fails to create.
+    #[arg(long, requires = "multiple")]
+    pub keep_going: bool,
+
+    /// Start the new worktree at the commit currently checked out in another worktree.
+    ///
+    /// The worktree can be given by name (its directory name or branch), by path, or by an
+    /// unambiguous prefix of a name.
+    #[arg(long, value_name = "WORKTREE", conflicts_with = "commitish")]
+    pub from_head_of: Option<String>,
+
+    /// If the branch is already checked out in another worktree, create a detached worktree at
+    /// its commit instead of failing.
+    ///
+    /// The new worktree is named after the branch with a `-detached` suffix, unless another
+    /// name is given.
+    #[arg(long, conflicts_with = "detach")]
+    pub checkout_detach_if_branch_busy: bool,
+
+    /// Pick the commit to start the new worktree at from a list of recently-committed-to local
+    /// branches, shown with the subjects of their latest commits.
+    ///
+    /// This requires an interactive terminal.
+    #[arg(long, conflicts_with_all = ["commitish", "from_head_of", "fetch"])]
+    pub pick_base: bool,
+
+    /// Fetch a branch from a repository URL and start the new worktree at it, without adding a
+    /// remote.
+    ///
+    /// This is written like `URL:BRANCH`, e.g. `git@github.com:puppy/doggy.git:silly-branch`.
+    #[arg(
+        long,
+        value_name = "URL:BRANCH",
+        conflicts_with_all = ["commitish", "from_head_of"],
+    )]
+    pub fetch: Option<UrlBranch>,
+
+    /// Start new branches at the default branch of the remote the current branch tracks.
+    ///
+    /// For example, when run from a worktree tracking `upstream/main`, the new branch starts at
+    /// (and tracks) `upstream`'s default branch instead of the usual default branch. This only
+    /// applies when no `COMMITISH` is given. Defaults to the `add.inherit_remote` setting.
+    #[arg(long, overrides_with = "no_inherit_remote")]
+    inherit_remote: bool,
+
+    /// Start new branches at the usual default branch, even if the `add.inherit_remote` setting
+    /// is enabled.
+    #[arg(long, overrides_with = "inherit_remote")]
+    no_inherit_remote: bool,
+
+    /// Only check out a branch that exists on a remote, rather than creating a new local branch.
+    ///
+    /// The branch is given by `COMMITISH` or the last component of `NAME_OR_PATH`. If it isn't
+    /// found on a remote, an error is reported instead of creating a new branch at the default
+    /// starting point.
+    #[arg(
+        long,
+        conflicts_with_all = ["branch", "force_branch", "detach", "from_head_of", "fetch"],
+    )]
+    pub track_remote_only: bool,
+
+    /// Set `refs/remotes/<remote>/HEAD` for the preferred remote if it's missing, so that later
+    /// commands can find the default branch without asking the remote.
+    ///
+    /// This uses `git remote set-head <remote> --auto`.
+    #[arg(long)]
+    pub set_head_remote: bool,
+
     /// Extra arguments to forward to `git worktree add`.
+    ///
+    /// These are passed before the new worktree's path, e.g. `git prole add puppy -- --lock`.
+    /// Arguments which `git prole` sets itself (like `-b` or `--detach`) may conflict with the
+    /// generated command; use the corresponding `git prole add` options instead.
     #[arg(last = true)]
     pub worktree_add_args: Vec<String>,
 }
 
+impl AddArgs {
+    /// `--copy-ignored-symlinks` or `--no-copy-ignored-symlinks`, if either was given.
+    pub fn copy_ignored_symlinks(&self) -> Option<bool> {
+        flag_pair(self.copy_ignored_symlinks, self.no_copy_ignored_symlinks)
+    }
+
+    /// `--link-worktrees` or `--no-link-worktrees`, if either was given.
+    pub fn link_worktrees(&self) -> Option<bool> {
+        flag_pair(self.link_worktrees, self.no_link_worktrees)
+    }
+
+    /// `--inherit-remote` or `--no-inherit-remote`, if either was given.
+    pub fn inherit_remote(&self) -> Option<bool> {
+        flag_pair(self.inherit_remote, self.no_inherit_remote)
+    }
+}
+
+/// Resolve a `--x`/`--no-x` flag pair, or `None` if neither was given so that a setting can be
+/// used instead.
+///
+/// The flags override each other, so at most one of them is set.
+fn flag_pair(yes: bool, no: bool) -> Option<bool> {
+    match (yes, no) {
+        (true, _) => Some(true),
+        (_, true) => Some(false),
+        (false, false) => None,
+    }
+}
+
 #[derive(Args, Clone, Debug)]
 #[group(required = true, multiple = true)]
 pub struct AddArgsInner {
@@ -162,6 +597,8 @@ pub struct AddArgsInner {
 
     /// Create a new branch with the given name, overwriting any existing branch with the same
     /// name.
+    ///
+    /// This will refuse to reset a branch which is checked out in another worktree.
     #[arg(
         long,
         short = 'B',
@@ -171,7 +608,24 @@ pub struct AddArgsInner {
     )]
     pub force_branch: Option<String>,
 
+    /// Create a new branch for a GitHub issue, named according to the
+    /// `add.issue_branch_template` setting.
+    ///
+    /// If `gh` is installed, the issue's title is fetched and appended to the branch name, like
+    /// `issue-42-fix-the-thing`.
+    #[arg(
+        long,
+        value_name = "NUMBER",
+        conflicts_with_all = ["branch", "force_branch", "detach"],
+    )]
+    pub issue: Option<u64>,
+
     /// Create the new worktree in detached mode, not checked out on any branch.
+    ///
+    /// If `NAME_OR_PATH` is a tag or branch and no `COMMITISH` is given, the worktree is
+    /// started at that ref and named after it, e.g. `git prole add --detach v2.0.0`. If
+    /// `NAME_OR_PATH` isn't given, the worktree is named `work`, `work-2`, etc., according to
+    /// the `add.scratch_prefix` setting.
     #[arg(
         long,
         short = 'd',
@@ -186,10 +640,126 @@ pub struct AddArgsInner {
     /// worktree name: it's used as a name in the same directory as the other worktrees, and (by
     /// default) a branch with that name is checked out or created. (When this is a path, only the
     /// last component of the path is used as the branch name.)
+    ///
+    /// Paths starting with `@/` are relative to the worktree container, regardless of the current
+    /// directory; e.g. `git prole add @/experiments/puppy` creates a worktree in
+    /// `experiments/puppy` next to the other worktrees.
     #[arg()]
     pub name_or_path: Option<String>,
 }
 
+#[derive(Args, Clone, Debug)]
+pub struct PopulateArgs {
+    /// The worktree to check out files in.
+    ///
+    /// This may be a path, a worktree directory name, a branch name, or an unambiguous prefix of
+    /// a directory name, branch name, or detached commit hash.
+    #[arg()]
+    pub worktree: String,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct ListArgs {
+    /// Print the worktrees in a stable format for scripts.
+    ///
+    /// This is the same as `git worktree list --porcelain -z`, except that a `dirty` attribute
+    /// is added to worktrees with uncommitted changes.
+    #[arg(long)]
+    pub porcelain: bool,
+
+    /// Print the worktrees as JSON.
+    ///
+    /// The JSON is an object with a `schema_version` field, which is incremented when the format
+    /// changes incompatibly, and a `worktrees` array. Each worktree has `path`, `head` (`bare`,
+    /// `detached`, or `branch`), `commit`, `branch`, `upstream`, `is_main`, `dirty`, `locked`,
+    /// and `prunable` fields.
+    #[arg(long, conflicts_with = "porcelain")]
+    pub json: bool,
+
+    /// Print canonical absolute paths to worktrees.
+    ///
+    /// By default, paths are shown relative to the current directory or your home directory.
+    #[arg(long, conflicts_with_all = ["porcelain", "json", "relative"])]
+    pub absolute: bool,
+
+    /// Print paths to worktrees relative to the worktree container.
+    ///
+    /// Worktrees outside of the container are shown with absolute paths.
+    #[arg(long, conflicts_with_all = ["porcelain", "json"])]
+    pub relative: bool,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct RemoveArgs {
+    /// Also delete the worktree's branch, with `git branch --delete`.
+    ///
+    /// Branches which aren't merged aren't deleted.
+    #[arg(long, short)]
+    pub branch: bool,
+
+    /// Remove the worktree even if it has uncommitted changes or you're in it.
+    ///
+    /// This passes `--force` to `git worktree remove`.
+    #[arg(long, short)]
+    pub force: bool,
+
+    /// Remove the worktree even if it's outside of the `safe_roots` setting's directories.
+    #[arg(long)]
+    pub allow_outside: bool,
+
+    /// The worktree to remove, given by name (its directory name or branch), by path, or by an
+    /// unambiguous prefix of a name.
+    pub name_or_path: String,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct CdArgs {
+    /// The worktree to print the path of.
+    ///
+    /// This may be a path, a worktree directory name, a branch name, or an unambiguous prefix of
+    /// a directory name, branch name, or detached commit hash. If omitted, the current worktree
+    /// or the worktree for the default branch is used.
+    pub name_or_path: Option<String>,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct PruneArgs {
+    /// Only prune worktrees older than this time, like `2.weeks.ago`.
+    ///
+    /// This is passed to `git worktree prune --expire`.
+    #[arg(long)]
+    pub expire: Option<String>,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct VerifyArgs {
+    /// Repair broken links with `git worktree repair`.
+    ///
+    /// This also finds worktrees which were moved without `git worktree move`, as long as they're
+    /// still in the worktree container.
+    #[arg(long)]
+    pub fix: bool,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct WhereamiArgs {
+    /// Print the information as JSON.
+    ///
+    /// The JSON includes a `schema_version` field, which is incremented when the format changes
+    /// incompatibly.
+    #[arg(long)]
+    pub json: bool,
+
+    /// Print the information in a stable format for scripts.
+    ///
+    /// Each field is printed on its own line as `KEY VALUE`, in the order `worktree`,
+    /// `container`, `git_dir`, `branch`. Every key is always printed; the value is empty if it's
+    /// unknown. Unlike the default output, this format is stable; new keys will only be added at
+    /// the end.
+    #[arg(long, conflicts_with = "json")]
+    pub porcelain: bool,
+}
+
 #[derive(Debug, Clone, Subcommand)]
 pub enum ConfigCommand {
     /// Initialize a default configuration file.
@@ -201,4 +771,11 @@ pub struct ConfigInitArgs {
     /// The location to write the configuration file. Can be `-` for stdout. Defaults to
     /// `~/.config/git-prole/config.toml`.
     pub output: Option<Utf8PathBuf>,
+
+    /// If the configuration file already exists, append any settings it's missing (commented
+    /// out, with their default values) instead of failing.
+    ///
+    /// Existing settings are left untouched.
+    #[arg(long)]
+    pub merge: bool,
 }
@@ -4,6 +4,10 @@ use std::process::Command;
 use camino::Utf8Path;
 use command_error::CommandExt;
 use miette::miette;
+use miette::Context;
+use miette::IntoDiagnostic;
+use owo_colors::OwoColorize;
+use owo_colors::Stream;
 use which::which_global;
 
 use crate::app_git::AppGit;
@@ -13,6 +17,8 @@ use crate::convert::ConvertPlanOpts;
 use crate::current_dir::current_dir_utf8;
 use crate::gh::looks_like_gh_url;
 use crate::git::repository_url_destination;
+use crate::git::GitLike;
+use crate::PathDisplay;
 
 pub fn clone<C>(git: AppGit<'_, C>, args: CloneArgs) -> miette::Result<()>
 where
@@ -34,21 +40,119 @@ where
         // TODO: Test this!!!
         Command::new("gh")
             .args(["repo", "clone", &args.repository, destination.as_str()])
-            .args(args.clone_args)
+            .args(&args.clone_args)
             .status_checked()?;
     } else {
-        // Test case: `clone_simple`.
-        git.clone_repository(&args.repository, Some(&destination), &args.clone_args)?;
+        // Test cases: `clone_simple`, `clone_quiet`.
+        git.clone_repository(
+            &args.repository,
+            Some(&destination),
+            git.config.cli.verbosity(),
+            &args.clone_args,
+        )?;
     }
 
+    verify_clone(&git, &destination)?;
+    set_default_remote(&git, &args, &destination)?;
+
     ConvertPlan::new(
         git.with_current_dir(destination),
         ConvertPlanOpts {
             default_branch: None,
             destination: None,
+            container: None,
+            no_fetch: false,
+            default_worktree_name_from_dir: false,
+            skip_default_worktree: false,
+            git_dir_name: None,
+            no_hooks: false,
+            bare: git.config.file.convert.bare(),
+            // `git clone` already put the repository here.
+            allow_outside: true,
+            // We just cloned the repository, so there's nothing to lose.
+            yes: true,
         },
     )?
     .execute()?;
 
     Ok(())
 }
+
+/// Check that the repository was actually cloned into `destination`, so that we don't convert
+/// some other directory into a worktree repository.
+///
+/// This can happen if arguments passed through to `git clone` or `gh repo clone` change where
+/// the repository is cloned to.
+fn verify_clone<C>(git: &AppGit<'_, C>, destination: &Utf8Path) -> miette::Result<()>
+where
+    C: AsRef<Utf8Path>,
+{
+    if !destination.exists() {
+        return Err(miette!(
+            "Expected the repository to be cloned into {}, but it doesn't exist",
+            destination.display_path_cwd()
+        ));
+    }
+
+    let git = git.with_current_dir(destination);
+    let root = git.path().repo_root_display().wrap_err_with(|| {
+        format!(
+            "Expected a Git repository to be cloned into {}",
+            destination.display_path_cwd()
+        )
+    })?;
+
+    // Test: `clone_destination_mismatch`
+    if root.canonicalize_utf8().into_diagnostic()?
+        != destination.canonicalize_utf8().into_diagnostic()?
+    {
+        return Err(miette!(
+            "Expected the repository to be cloned into {}, but it's in {}",
+            destination.display_path_cwd(),
+            root.display_path_cwd()
+        ));
+    }
+
+    if git.refs().parse("HEAD")?.is_none() {
+        return Err(miette!(
+            "Cloned repository in {} has no commits",
+            destination.display_path_cwd()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Set `checkout.defaultRemote` in the new repository, if requested.
+fn set_default_remote<C>(
+    git: &AppGit<'_, C>,
+    args: &CloneArgs,
+    destination: &Utf8Path,
+) -> miette::Result<()>
+where
+    C: AsRef<Utf8Path>,
+{
+    let remote = match args
+        .default_remote
+        .as_deref()
+        .or_else(|| git.config.file.clone.default_remote())
+    {
+        Some(remote) => remote,
+        None => {
+            return Ok(());
+        }
+    };
+
+    let git = git.with_current_dir(destination);
+    if !git.remote().list()?.iter().any(|name| name == remote) {
+        // Test: `clone_default_remote_missing`
+        tracing::warn!(
+            "Remote {} doesn't exist in {}; setting `checkout.defaultRemote` anyways",
+            remote.if_supports_color(Stream::Stdout, |text| text.cyan()),
+            destination.display_path_cwd()
+        );
+    }
+
+    // Test: `clone_default_remote`
+    git.config().set("checkout.defaultRemote", remote)
+}
@@ -0,0 +1,138 @@
+//! VS Code multi-root workspace files, like `my-repo.code-workspace`.
+//!
+//! See: <https://code.visualstudio.com/docs/editor/multi-root-workspaces>
+
+use camino::Utf8Path;
+use camino::Utf8PathBuf;
+use miette::miette;
+use miette::Context;
+use miette::IntoDiagnostic;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::fs;
+use crate::PathDisplay;
+
+/// Add `folder` to the `folders` in the `workspace` file, if it isn't already listed.
+///
+/// If `workspace` doesn't exist, it's created.
+pub fn add_folder(workspace: &Utf8Path, folder: &Utf8Path) -> miette::Result<()> {
+    let workspace_dir = workspace
+        .parent()
+        .ok_or_else(|| miette!("Workspace file has no parent: {workspace}"))?;
+
+    let mut contents = if workspace.exists() {
+        parse(&fs::read_to_string(workspace)?).wrap_err_with(|| {
+            format!(
+                "Failed to parse VS Code workspace {}",
+                workspace.display_path_cwd()
+            )
+        })?
+    } else {
+        json!({ "folders": [] })
+    };
+
+    let folders = contents
+        .as_object_mut()
+        .ok_or_else(|| miette!("VS Code workspace {workspace} is not a JSON object"))?
+        .entry("folders")
+        .or_insert_with(|| json!([]))
+        .as_array_mut()
+        .ok_or_else(|| miette!("`folders` in VS Code workspace {workspace} is not an array"))?;
+
+    let already_listed = folders.iter().any(|entry| {
+        entry
+            .get("path")
+            .and_then(Value::as_str)
+            .is_some_and(|path| workspace_dir.join(path) == folder)
+    });
+    if already_listed {
+        tracing::debug!(%workspace, %folder, "Folder is already in VS Code workspace");
+        return Ok(());
+    }
+
+    let path = folder
+        .strip_prefix(workspace_dir)
+        .map(Utf8PathBuf::from)
+        .unwrap_or_else(|_| folder.to_owned());
+    folders.push(json!({ "path": path }));
+
+    let mut serialized = serde_json::to_string_pretty(&contents).into_diagnostic()?;
+    serialized.push('\n');
+    fs::write(workspace, serialized)?;
+    Ok(())
+}
+
+fn parse(contents: &str) -> miette::Result<Value> {
+    serde_json::from_str(contents).map_err(|err| {
+        if err.is_syntax() && (contents.contains("//") || contents.contains("/*")) {
+            miette!(
+                "{err}\nComments and trailing commas aren't supported; remove them so the \
+                 workspace can be updated"
+            )
+        } else {
+            miette!("{err}")
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_add_folder() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let dir = Utf8Path::from_path(tempdir.path()).unwrap();
+        let workspace = dir.join("my-repo.code-workspace");
+        fs::write(
+            &workspace,
+            r#"{"settings": {"editor.tabSize": 2}, "folders": [{"path": "main"}]}"#,
+        )
+        .unwrap();
+
+        add_folder(&workspace, &dir.join("puppy")).unwrap();
+        // Adding a folder twice is a no-op.
+        add_folder(&workspace, &dir.join("puppy")).unwrap();
+        add_folder(&workspace, &dir.join("main")).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&workspace).unwrap(),
+            indoc::indoc!(
+                r#"
+                {
+                  "settings": {
+                    "editor.tabSize": 2
+                  },
+                  "folders": [
+                    {
+                      "path": "main"
+                    },
+                    {
+                      "path": "puppy"
+                    }
+                  ]
+                }
+                "#
+            )
+        );
+    }
+
+    #[test]
+    fn test_add_folder_comments() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let dir = Utf8Path::from_path(tempdir.path()).unwrap();
+        let workspace = dir.join("my-repo.code-workspace");
+        fs::write(&workspace, "{\n  // Puppy!\n  \"folders\": []\n}\n").unwrap();
+
+        let error = format!(
+            "{:?}",
+            add_folder(&workspace, &dir.join("puppy")).unwrap_err()
+        );
+        assert!(
+            error.contains("Comments and trailing commas aren't supported"),
+            "{error}"
+        );
+    }
+}
@@ -1,5 +1,7 @@
+use std::collections::BTreeMap;
 use std::process::Command;
 
+use camino::Utf8Path;
 use camino::Utf8PathBuf;
 use clap::Parser;
 use miette::Context;
@@ -12,13 +14,14 @@ use xdg::BaseDirectories;
 
 use crate::cli::Cli;
 use crate::fs;
+use crate::glob::Glob;
 use crate::install_tracing::install_tracing;
+use crate::CommitHash;
 
 /// Configuration, both from the command-line and a user configuration file.
 #[derive(Debug)]
 pub struct Config {
     /// User directories.
-    #[expect(dead_code)]
     pub(crate) dirs: BaseDirectories,
     /// User configuration file.
     pub file: ConfigFile,
@@ -35,7 +38,11 @@ impl Config {
     pub fn new() -> miette::Result<Self> {
         let cli = Cli::parse();
         // TODO: add tracing settings to the config file
-        install_tracing(&cli.log)?;
+        if cli.no_color {
+            // Test: `no_color`
+            owo_colors::set_override(false);
+        }
+        install_tracing(cli.log_filter(), !cli.no_color)?;
         let dirs = BaseDirectories::with_prefix("git-prole").into_diagnostic()?;
         // TODO: Use `git config` for configuration?
         let path = cli
@@ -54,6 +61,7 @@ impl Config {
                 .wrap_err("Failed to deserialize configuration file")?
             }
         };
+        CommitHash::set_abbrev_len(file.display.abbrev_len());
         Ok(Self {
             dirs,
             path,
@@ -77,6 +85,109 @@ impl Config {
     }
 }
 
+impl Config {
+    /// Find the settings in the default configuration file which are missing from the given
+    /// configuration file contents.
+    ///
+    /// The missing settings are returned with their default values as a commented-out TOML
+    /// section, suitable for appending to the configuration file. If no settings are missing,
+    /// `None` is returned.
+    pub fn missing_defaults(contents: &str) -> miette::Result<Option<String>> {
+        let mut existing: toml::Table = toml::from_str(contents)
+            .into_diagnostic()
+            .wrap_err("Failed to deserialize configuration file")?;
+        // Settings we've already added in a previous merge don't need to be added again.
+        for section in merged_sections(contents) {
+            if let Ok(section) = toml::from_str(&section) {
+                merge_tables(&mut existing, section);
+            }
+        }
+        let default: toml::Table = toml::from_str(Self::DEFAULT)
+            .into_diagnostic()
+            .wrap_err("Failed to deserialize default configuration file")?;
+
+        let missing = missing_keys(default, &existing);
+        if missing.is_empty() {
+            return Ok(None);
+        }
+
+        let missing = toml::to_string(&missing)
+            .into_diagnostic()
+            .wrap_err("Failed to serialize missing settings")?;
+
+        let mut section = format!("\n{MERGE_HEADER}");
+        for line in missing.lines() {
+            if line.is_empty() {
+                section.push_str("#\n");
+            } else {
+                section.push_str("# ");
+                section.push_str(line);
+                section.push('\n');
+            }
+        }
+        Ok(Some(section))
+    }
+}
+
+/// The header of a section added by [`Config::missing_defaults`].
+const MERGE_HEADER: &str = "\
+    # Settings added by `git prole config init --merge`, with their default values.\n\
+    # To use them, uncomment them and move them into the right section above.\n";
+
+/// Get the uncommented contents of the sections previously added by
+/// [`Config::missing_defaults`].
+fn merged_sections(contents: &str) -> Vec<String> {
+    contents
+        .split(MERGE_HEADER)
+        .skip(1)
+        .map(|section| {
+            section
+                .lines()
+                .map_while(|line| line.strip_prefix('#'))
+                .map(|line| line.strip_prefix(' ').unwrap_or(line))
+                .fold(String::new(), |mut section, line| {
+                    section.push_str(line);
+                    section.push('\n');
+                    section
+                })
+        })
+        .collect()
+}
+
+/// Recursively merge the keys in `other` into `table`.
+fn merge_tables(table: &mut toml::Table, other: toml::Table) {
+    for (key, value) in other {
+        match (table.get_mut(&key), value) {
+            (Some(toml::Value::Table(table)), toml::Value::Table(other)) => {
+                merge_tables(table, other);
+            }
+            (Some(_), _) => {}
+            (None, value) => {
+                table.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Get the keys in `default` which are missing from `existing`, recursing into tables.
+fn missing_keys(default: toml::Table, existing: &toml::Table) -> toml::Table {
+    default
+        .into_iter()
+        .filter_map(|(key, value)| match (value, existing.get(&key)) {
+            (value, None) => Some((key, value)),
+            (toml::Value::Table(default), Some(toml::Value::Table(existing))) => {
+                let missing = missing_keys(default, existing);
+                if missing.is_empty() {
+                    None
+                } else {
+                    Some((key, toml::Value::Table(missing)))
+                }
+            }
+            (_, Some(_)) => None,
+        })
+        .collect()
+}
+
 fn config_file_path(dirs: &BaseDirectories) -> miette::Result<Utf8PathBuf> {
     dirs.get_config_file(ConfigFile::FILE_NAME)
         .try_into()
@@ -97,8 +208,14 @@ fn config_file_path(dirs: &BaseDirectories) -> miette::Result<Utf8PathBuf> {
 pub struct ConfigFile {
     remote_names: Vec<String>,
     branch_names: Vec<String>,
+    worktree_parent_depth: Option<usize>,
+    repo_root_markers: Vec<String>,
+    safe_roots: Vec<String>,
+    remote_branch_names: BTreeMap<String, Vec<String>>,
+    pub convert: ConvertConfig,
     pub clone: CloneConfig,
     pub add: AddConfig,
+    pub display: DisplayConfig,
 }
 
 impl ConfigFile {
@@ -121,18 +238,86 @@ impl ConfigFile {
             self.branch_names.clone()
         }
     }
+
+    /// Default branch names to try for the given remote, before asking the remote.
+    pub fn remote_branch_names(&self, remote: &str) -> &[String] {
+        self.remote_branch_names
+            .get(remote)
+            .map(|names| names.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// How many levels above the main worktree the worktree container is.
+    pub fn worktree_parent_depth(&self) -> usize {
+        self.worktree_parent_depth.unwrap_or(1).max(1)
+    }
+
+    /// Files or directories which mark the root of the worktree container.
+    pub fn repo_root_markers(&self) -> &[String] {
+        &self.repo_root_markers
+    }
+
+    /// Directories which `convert` and `remove` are allowed to operate in, unless
+    /// `--allow-outside` is given. If empty, there are no restrictions.
+    pub fn safe_roots(&self) -> &[String] {
+        &self.safe_roots
+    }
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ConvertConfig {
+    git_dir_name: Option<String>,
+    bare: Option<bool>,
+    commands: Vec<ShellCommand>,
+}
+
+impl ConvertConfig {
+    /// The name of the bare Git directory in the worktree container.
+    pub fn git_dir_name(&self) -> &str {
+        self.git_dir_name.as_deref().unwrap_or(".git")
+    }
+
+    /// Whether to make the repository bare when converting it.
+    ///
+    /// Otherwise, the main worktree keeps the Git directory.
+    pub fn bare(&self) -> bool {
+        self.bare.unwrap_or(true)
+    }
+
+    /// Commands to run in the worktree container after a repository is converted.
+    pub fn commands(&self) -> &[ShellCommand] {
+        &self.commands
+    }
 }
 
 #[derive(Debug, Default, Deserialize, PartialEq, Eq)]
 #[serde(default)]
 pub struct CloneConfig {
     enable_gh: Option<bool>,
+    default_remote: Option<String>,
 }
 
 impl CloneConfig {
     pub fn enable_gh(&self) -> bool {
         self.enable_gh.unwrap_or(false)
     }
+
+    pub fn default_remote(&self) -> Option<&str> {
+        self.default_remote.as_deref()
+    }
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct DisplayConfig {
+    abbrev_len: Option<usize>,
+}
+
+impl DisplayConfig {
+    pub fn abbrev_len(&self) -> usize {
+        self.abbrev_len.unwrap_or(CommitHash::DEFAULT_ABBREV_LEN)
+    }
 }
 
 #[derive(Debug, Default, Deserialize, PartialEq, Eq)]
@@ -140,8 +325,24 @@ impl CloneConfig {
 pub struct AddConfig {
     copy_untracked: Option<bool>,
     copy_ignored: Option<bool>,
+    copy_ignored_include: Vec<Glob>,
+    copy_ignored_exclude: Vec<Glob>,
+    copy_ignored_symlinks: Option<bool>,
     commands: Vec<ShellCommand>,
+    commands_fail_fast: Option<bool>,
     branch_replacements: Vec<BranchReplacement>,
+    branch_name_replacements: Vec<BranchReplacement>,
+    tmux_window_name: Option<String>,
+    direnv_allow: Option<bool>,
+    setup_tools: Option<bool>,
+    share_submodules: Option<bool>,
+    link_siblings: Option<bool>,
+    inherit_remote: Option<bool>,
+    hooks_dir: Option<Utf8PathBuf>,
+    code_workspace: Option<Utf8PathBuf>,
+    lock_reason_template: Option<String>,
+    issue_branch_template: Option<String>,
+    scratch_prefix: Option<String>,
 }
 
 impl AddConfig {
@@ -153,13 +354,90 @@ impl AddConfig {
         self.copy_ignored.unwrap_or(true)
     }
 
+    /// If non-empty, only ignored files matching one of these patterns are copied.
+    pub fn copy_ignored_include(&self) -> &[Glob] {
+        &self.copy_ignored_include
+    }
+
+    /// Ignored files matching one of these patterns aren't copied.
+    pub fn copy_ignored_exclude(&self) -> &[Glob] {
+        &self.copy_ignored_exclude
+    }
+
+    /// Whether symlinks are copied along with ignored files.
+    pub fn copy_ignored_symlinks(&self) -> bool {
+        self.copy_ignored_symlinks.unwrap_or(true)
+    }
+
     pub fn commands(&self) -> &[ShellCommand] {
         &self.commands
     }
 
+    pub fn commands_fail_fast(&self) -> bool {
+        self.commands_fail_fast.unwrap_or(false)
+    }
+
     pub fn branch_replacements(&self) -> &[BranchReplacement] {
         &self.branch_replacements
     }
+
+    /// Replacements applied to the names of new branches, before they're created.
+    ///
+    /// These are separate from [`Self::branch_replacements`], which only affect directory names.
+    pub fn branch_name_replacements(&self) -> &[BranchReplacement] {
+        &self.branch_name_replacements
+    }
+
+    /// The template for a new worktree's `tmux` window name.
+    pub fn tmux_window_name(&self) -> &str {
+        self.tmux_window_name.as_deref().unwrap_or("{name}")
+    }
+
+    pub fn direnv_allow(&self) -> bool {
+        self.direnv_allow.unwrap_or(false)
+    }
+
+    pub fn setup_tools(&self) -> bool {
+        self.setup_tools.unwrap_or(false)
+    }
+
+    pub fn share_submodules(&self) -> bool {
+        self.share_submodules.unwrap_or(false)
+    }
+
+    pub fn link_siblings(&self) -> bool {
+        self.link_siblings.unwrap_or(false)
+    }
+
+    pub fn inherit_remote(&self) -> bool {
+        self.inherit_remote.unwrap_or(false)
+    }
+
+    pub fn hooks_dir(&self) -> Option<&Utf8Path> {
+        self.hooks_dir.as_deref()
+    }
+
+    /// A VS Code workspace file to add new worktrees to, relative to the worktree container.
+    pub fn code_workspace(&self) -> Option<&Utf8Path> {
+        self.code_workspace.as_deref()
+    }
+
+    /// The template for the lock reason of worktrees created with `git prole add --lock`.
+    pub fn lock_reason_template(&self) -> &str {
+        self.lock_reason_template
+            .as_deref()
+            .unwrap_or("created by git-prole on {date} for {branch}")
+    }
+
+    /// The template for branch names created with `git prole add --issue`.
+    pub fn issue_branch_template(&self) -> &str {
+        self.issue_branch_template.as_deref().unwrap_or("issue-{n}")
+    }
+
+    /// The name of scratch worktrees with a detached `HEAD`, before numbers are appended.
+    pub fn scratch_prefix(&self) -> &str {
+        self.scratch_prefix.as_deref().unwrap_or("work")
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
@@ -225,6 +503,17 @@ pub struct BranchReplacement {
     pub count: Option<usize>,
 }
 
+impl BranchReplacement {
+    /// Apply this replacement to the given input.
+    pub fn apply(&self, input: &str) -> String {
+        match self.count {
+            Some(count) => self.find.replacen(input, count, &self.replace),
+            None => self.find.replace_all(input, &self.replace),
+        }
+        .into_owned()
+    }
+}
+
 impl PartialEq for BranchReplacement {
     fn eq(&self, other: &Self) -> bool {
         self.replace == other.replace && self.find.as_str() == other.find.as_str()
@@ -244,8 +533,68 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use expect_test::expect;
+    use indoc::indoc;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn test_missing_defaults() {
+        assert_eq!(Config::missing_defaults(Config::DEFAULT).unwrap(), None);
+
+        expect![[r#"
+
+            # Settings added by `git prole config init --merge`, with their default values.
+            # To use them, uncomment them and move them into the right section above.
+            # branch_names = ["main", "master", "trunk"]
+            # repo_root_markers = []
+            # safe_roots = []
+            # worktree_parent_depth = 1
+            #
+            # [add]
+            # branch_name_replacements = []
+            # branch_replacements = []
+            # commands = []
+            # commands_fail_fast = false
+            # copy_ignored_exclude = []
+            # copy_ignored_include = []
+            # copy_ignored_symlinks = true
+            # direnv_allow = false
+            # inherit_remote = false
+            # issue_branch_template = "issue-{n}"
+            # link_siblings = false
+            # lock_reason_template = "created by git-prole on {date} for {branch}"
+            # scratch_prefix = "work"
+            # setup_tools = false
+            # share_submodules = false
+            # tmux_window_name = "{name}"
+            #
+            # [clone]
+            # enable_gh = false
+            #
+            # [convert]
+            # bare = true
+            # commands = []
+            # git_dir_name = ".git"
+            #
+            # [display]
+            # abbrev_len = 8
+            #
+            # [remote_branch_names]
+        "#]]
+        .assert_eq(
+            &Config::missing_defaults(indoc!(
+                r#"
+                remote_names = ["puppy"]
+
+                [add]
+                copy_ignored = false
+                "#
+            ))
+            .unwrap()
+            .unwrap(),
+        );
+    }
+
     #[test]
     fn test_default_config_file_parse() {
         let default_config = toml::from_str::<ConfigFile>(Config::DEFAULT).unwrap();
@@ -254,15 +603,46 @@ mod tests {
             ConfigFile {
                 remote_names: vec!["upstream".to_owned(), "origin".to_owned(),],
                 branch_names: vec!["main".to_owned(), "master".to_owned(), "trunk".to_owned(),],
+                worktree_parent_depth: Some(1),
+                repo_root_markers: vec![],
+                safe_roots: vec![],
+                remote_branch_names: BTreeMap::new(),
+                convert: ConvertConfig {
+                    git_dir_name: Some(".git".to_owned()),
+                    bare: Some(true),
+                    commands: vec![],
+                },
                 clone: CloneConfig {
-                    enable_gh: Some(false)
+                    enable_gh: Some(false),
+                    default_remote: None,
                 },
                 add: AddConfig {
                     copy_untracked: None,
                     copy_ignored: Some(true),
+                    copy_ignored_include: vec![],
+                    copy_ignored_exclude: vec![],
+                    copy_ignored_symlinks: Some(true),
                     commands: vec![],
+                    commands_fail_fast: Some(false),
                     branch_replacements: vec![],
-                }
+                    branch_name_replacements: vec![],
+                    tmux_window_name: Some("{name}".to_owned()),
+                    direnv_allow: Some(false),
+                    setup_tools: Some(false),
+                    share_submodules: Some(false),
+                    link_siblings: Some(false),
+                    inherit_remote: Some(false),
+                    hooks_dir: None,
+                    code_workspace: None,
+                    lock_reason_template: Some(
+                        "created by git-prole on {date} for {branch}".to_owned()
+                    ),
+                    issue_branch_template: Some("issue-{n}".to_owned()),
+                    scratch_prefix: Some("work".to_owned()),
+                },
+                display: DisplayConfig {
+                    abbrev_len: Some(8),
+                },
             }
         );
 
@@ -272,24 +652,55 @@ mod tests {
             ConfigFile {
                 remote_names: empty_config.remote_names(),
                 branch_names: empty_config.branch_names(),
+                worktree_parent_depth: Some(empty_config.worktree_parent_depth()),
+                repo_root_markers: empty_config.repo_root_markers().to_vec(),
+                safe_roots: empty_config.safe_roots().to_vec(),
+                remote_branch_names: empty_config.remote_branch_names.clone(),
+                convert: ConvertConfig {
+                    git_dir_name: Some(empty_config.convert.git_dir_name().to_owned()),
+                    bare: Some(empty_config.convert.bare()),
+                    commands: empty_config.convert.commands().to_vec(),
+                },
                 clone: CloneConfig {
                     enable_gh: Some(empty_config.clone.enable_gh()),
+                    default_remote: empty_config.clone.default_remote().map(ToOwned::to_owned),
                 },
                 add: AddConfig {
                     copy_untracked: None,
                     copy_ignored: Some(empty_config.add.copy_ignored()),
+                    copy_ignored_include: empty_config.add.copy_ignored_include().to_vec(),
+                    copy_ignored_exclude: empty_config.add.copy_ignored_exclude().to_vec(),
+                    copy_ignored_symlinks: Some(empty_config.add.copy_ignored_symlinks()),
                     commands: empty_config
                         .add
                         .commands()
                         .iter()
                         .map(|command| command.to_owned())
                         .collect(),
+                    commands_fail_fast: Some(empty_config.add.commands_fail_fast()),
                     branch_replacements: empty_config
                         .add
                         .branch_replacements()
                         .iter()
                         .map(|replacement| replacement.to_owned())
                         .collect(),
+                    branch_name_replacements: empty_config.add.branch_name_replacements().to_vec(),
+                    tmux_window_name: Some(empty_config.add.tmux_window_name().to_owned()),
+                    direnv_allow: Some(empty_config.add.direnv_allow()),
+                    setup_tools: Some(empty_config.add.setup_tools()),
+                    share_submodules: Some(empty_config.add.share_submodules()),
+                    link_siblings: Some(empty_config.add.link_siblings()),
+                    inherit_remote: Some(empty_config.add.inherit_remote()),
+                    hooks_dir: empty_config.add.hooks_dir().map(ToOwned::to_owned),
+                    code_workspace: empty_config.add.code_workspace().map(ToOwned::to_owned),
+                    lock_reason_template: Some(empty_config.add.lock_reason_template().to_owned()),
+                    issue_branch_template: Some(
+                        empty_config.add.issue_branch_template().to_owned()
+                    ),
+                    scratch_prefix: Some(empty_config.add.scratch_prefix().to_owned()),
+                },
+                display: DisplayConfig {
+                    abbrev_len: Some(empty_config.display.abbrev_len()),
                 },
             }
         );
@@ -1,10 +1,14 @@
 use std::borrow::Cow;
 use std::fmt::Debug;
 use std::fmt::Display;
+use std::io::IsTerminal;
 
 use camino::Utf8Path;
 use camino::Utf8PathBuf;
+use command_error::CommandExt;
+use command_error::Utf8ProgramAndArgs;
 use miette::miette;
+use miette::Context;
 use miette::IntoDiagnostic;
 use owo_colors::OwoColorize;
 use owo_colors::Stream;
@@ -19,6 +23,7 @@ use crate::git::BranchRef;
 use crate::git::GitLike;
 use crate::git::LocalBranchRef;
 use crate::only_paths_in_parent_directory;
+use crate::safe_roots::check_safe_roots;
 use crate::topological_sort::topological_sort;
 use crate::utf8absolutize::Utf8Absolutize;
 use crate::utf8tempdir::Utf8TempDir;
@@ -34,6 +39,24 @@ use crate::Worktrees;
 pub struct ConvertPlanOpts {
     pub default_branch: Option<String>,
     pub destination: Option<Utf8PathBuf>,
+    /// Where to place the worktrees, if not in the `destination`.
+    pub container: Option<Utf8PathBuf>,
+    /// Don't fetch the default branch from its remote.
+    pub no_fetch: bool,
+    /// Name the default branch's worktree after the repository's directory.
+    pub default_worktree_name_from_dir: bool,
+    /// Don't create a new worktree for the default branch.
+    pub skip_default_worktree: bool,
+    /// The name of the bare Git directory. Defaults to the `convert.git_dir_name` setting.
+    pub git_dir_name: Option<String>,
+    /// Don't run `convert.commands` after converting.
+    pub no_hooks: bool,
+    /// Make the repository bare. Otherwise, the main worktree keeps the Git directory.
+    pub bare: bool,
+    /// Convert the repository even if it's outside of the `safe_roots`.
+    pub allow_outside: bool,
+    /// Don't ask for confirmation before converting.
+    pub yes: bool,
 }
 
 #[derive(Debug)]
@@ -47,8 +70,13 @@ where
     tempdir: Utf8PathBuf,
     /// The destination where the worktree container will be created.
     destination: Utf8PathBuf,
+    /// The directory to place the worktrees in. This is usually the `destination`, but the
+    /// worktrees can be kept separately from the Git directory.
+    container: Utf8PathBuf,
     /// The path of the repository to create.
     repo: Utf8PathBuf,
+    /// The name of the bare Git directory in the `destination`.
+    git_dir_name: String,
     /// The plan for converting the repo to a bare repo.
     ///
     /// If this is `Some`, the main worktree is not yet bare.
@@ -62,6 +90,12 @@ where
     /// These contain unique names for each worktree, which are usually the name of the checked
     /// out branch.
     worktrees: Vec<WorktreePlan>,
+    /// The default branch, used to find its worktree for `convert.commands`.
+    default_branch: LocalBranchRef,
+    /// Don't run `convert.commands` after converting.
+    no_hooks: bool,
+    /// Don't ask for confirmation before converting.
+    yes: bool,
     /// New worktrees to create.
     ///
     /// This contains the default branch, unless it's already checked out.
@@ -74,6 +108,9 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if self.is_no_op() {
+            // Tests:
+            // - `convert_no_op`
+            // - `convert_no_op_nested`
             return write!(
                 f,
                 "{} is already a worktree repository",
@@ -92,6 +129,14 @@ where
             },
         )?;
 
+        if self.container != self.destination {
+            write!(
+                f,
+                "\nWorktrees will be placed in {}.",
+                self.container.display_path_cwd()
+            )?;
+        }
+
         let moves = self
             .worktrees
             .iter()
@@ -174,6 +219,26 @@ where
                     },
                 )?;
             }
+
+            if main_plan.stash {
+                // Test: `convert_uncommitted_changes`
+                write!(
+                    f,
+                    "\nI'll stash the changes in {} and restore them afterwards, so staged \
+                    changes stay staged.",
+                    main_plan.inner.worktree.path.display_path_cwd(),
+                )?;
+            }
+        }
+
+        if let Some(main) = self.non_bare_main() {
+            // Test: `convert_no_bare`
+            write!(
+                f,
+                "\nThe repository won't be made bare; the Git directory will stay in the main \
+                worktree, {}.",
+                main.destination(self).display_path_cwd(),
+            )?;
         }
 
         Ok(())
@@ -194,14 +259,15 @@ where
         //   (`convert_default_branch_checked_out`)
         // - We might _not_ have the default branch checked out.
         //   (`convert_non_default_branch_checked_out`)
-        // - We might have unstaged/uncommitted work.
-        //   TODO: The `git reset` causes staged changes to be lost; bring back the
-        //   `git status push`/`pop`?
+        // - We might have unstaged/uncommitted work. Staged changes would be lost to the
+        //   `git reset`, so they're stashed and restored.
         //   (`convert_uncommitted_changes`, `convert_unstaged_changes`)
         // - We might not be on _any_ branch.
         //   (`convert_detached_head`)
         // - There is no local branch for the default branch.
         //   (`config_default_branches`).
+        // - The default branch might not point to a commit, e.g. if `origin/HEAD` is dangling.
+        //   (`convert_default_branch_dangling`)
         //
         // Where do we want to place the resulting repo?
         // - If it's non-bare: in the default worktree's path
@@ -219,6 +285,44 @@ where
         let repo = git.path().repo_root_display()?;
         let worktrees = git.worktree().list()?;
 
+        // A main worktree on an unborn branch with no files in it (e.g. after `git init && git
+        // fetch`) has nothing to keep, so we remove it and check out the default branch instead.
+        //
+        // Tests:
+        // - `convert_unborn_head`
+        // - `convert_unborn_head_remote`
+        if let Some(worktree) = worktrees
+            .values()
+            .find(|worktree| worktree.head.is_unborn())
+            .filter(|worktree| {
+                // Without making the repository bare, the unborn main worktree has to keep the
+                // Git directory.
+                //
+                // Test: `convert_no_bare_unborn_head`
+                !worktree.is_main
+                    || !opts.bare
+                    || !git
+                        .with_current_dir(&worktree.path)
+                        .status()
+                        .get()
+                        .is_ok_and(|status| status.entries.is_empty())
+                    // Without any branches (e.g. after `git init` with no remote), there's
+                    // nothing to check out in its place.
+                    //
+                    // Test: `convert_unborn_no_remote`
+                    || !git
+                        .branch()
+                        .list()
+                        .is_ok_and(|branches| !branches.is_empty())
+            })
+        {
+            return Err(miette!(
+                "{} has no commits yet; make an initial commit before converting {} to a worktree repository",
+                worktree.path.display_path_cwd(),
+                repo.display_path_cwd(),
+            ));
+        }
+
         let destination = Self::destination_plan(&worktrees, &opts)?;
         let destination_name = destination
             .file_name()
@@ -229,6 +333,42 @@ where
             .to_path_buf();
         tracing::debug!(%destination, "Destination determined");
 
+        let container = match &opts.container {
+            // Test: `convert_container`
+            Some(container) => container
+                .absolutize()
+                .map(Cow::into_owned)
+                .into_diagnostic()?,
+            None => destination.clone(),
+        };
+
+        // Tests:
+        // - `config_safe_roots`
+        // - `config_safe_roots_default`
+        check_safe_roots(
+            &git.config.file,
+            opts.allow_outside,
+            [&repo, &destination, &container]
+                .into_iter()
+                .chain(worktrees.keys())
+                .map(|path| path.as_path()),
+        )?;
+
+        let git_dir_name = opts
+            .git_dir_name
+            .clone()
+            .unwrap_or_else(|| git.config.file.convert.git_dir_name().to_owned());
+        if git_dir_name.is_empty()
+            || git_dir_name == "."
+            || git_dir_name == ".."
+            || git_dir_name.contains('/')
+        {
+            // Test: `convert_git_dir_name_invalid`
+            return Err(miette!(
+                "Git directory name must be a single path component: {git_dir_name:?}"
+            ));
+        }
+
         let tempdir = Utf8TempDir::new(&destination_parent)?.into_path();
 
         let default_branch = match opts.default_branch {
@@ -240,38 +380,72 @@ where
                 .rev_parse_symbolic_full_name(&default_branch)?
                 .ok_or_else(|| miette!("`--default-branch` not found: {default_branch}"))?
                 .try_into()?,
-            None => git.branch().preferred()?.ok_or_else(|| {
+            None => git.main_branch()?.ok_or_else(|| {
                 miette!("No default branch found; specify a `--default-branch` to check out")
             })?,
         };
         tracing::debug!(%default_branch, "Default branch determined");
 
+        let default_worktree_name = if opts.default_worktree_name_from_dir {
+            Some(
+                repo.file_name()
+                    .ok_or_else(|| miette!("Repository has no basename: {repo}"))?
+                    .to_owned(),
+            )
+        } else {
+            None
+        };
+
         // TODO: Check for branch with the default as an upstream as well?
         //
         // Tests:
         // - `convert_default_branch_checked_out`
         // - `convert_non_default_branch_checked_out`
-        let has_worktree_for_default_branch =
-            worktrees.for_branch(&default_branch.as_local()).is_some();
+        let has_worktree_for_default_branch = worktrees
+            .for_branch(&default_branch.as_local())
+            .is_some_and(|worktree| !worktree.head.is_unborn());
         let new_worktrees = if has_worktree_for_default_branch {
             Vec::new()
+        } else if opts.skip_default_worktree {
+            // Test: `convert_skip_default_worktree`
+            tracing::debug!(%default_branch, "Not creating a worktree for the default branch");
+            Vec::new()
         } else {
-            let name = git
-                .worktree()
-                .dirname_for(default_branch.branch_name())
-                .into_owned();
+            // The default branch might not point to a commit, e.g. a dangling
+            // `refs/remotes/origin/HEAD` symbolic ref, or a local branch with no commits.
+            //
+            // Test: `convert_default_branch_dangling`
+            let resolved = git.refs().parse(&format!("{default_branch:#}"))?.is_some();
 
             // If we're creating a worktree for a default branch from a
             // remote, we may not have a corresponding local branch
             // yet.
-            let (create_branch, start_point) = match &default_branch {
-                BranchRef::Local(_) => (None, default_branch),
+            let start_point = match &default_branch {
+                BranchRef::Local(_) => resolved.then(|| (None, default_branch.clone())),
                 BranchRef::Remote(remote_branch) => {
                     if git.branch().exists_local(remote_branch.branch_name())? {
                         // Test: `convert_multiple_remotes`
-                        (None, BranchRef::Local(remote_branch.as_local()))
-                    } else {
-                        // Test: `convert_no_local_default_branch`
+                        Some((None, BranchRef::Local(remote_branch.as_local())))
+                    } else if opts.no_fetch {
+                        if resolved {
+                            // Test: `convert_no_fetch`
+                            Some((Some(remote_branch.as_local()), default_branch.clone()))
+                        } else {
+                            // Test: `convert_no_fetch_not_fetched`
+                            return Err(miette!(
+                                "Default branch {remote_branch} only exists on the remote; \
+                                run without `--no-fetch` or pass `--default-branch` with a local branch"
+                            ));
+                        }
+                    } else if resolved
+                        || git
+                            .remote()
+                            .has_branch(remote_branch.remote(), &remote_branch.as_local())?
+                    {
+                        // Tests:
+                        // - `convert_no_local_default_branch`
+                        // - `convert_fetch_fails`
+                        // - `config_remote_names` (not fetched yet)
                         tracing::warn!(
                             %remote_branch,
                             "Fetching the default branch"
@@ -280,16 +454,33 @@ where
                             remote_branch.remote(),
                             Some(&format!("{:#}:{remote_branch:#}", remote_branch.as_local())),
                         )?;
-                        (Some(remote_branch.as_local()), default_branch)
+                        Some((Some(remote_branch.as_local()), default_branch.clone()))
+                    } else {
+                        None
                     }
                 }
             };
 
-            vec![NewWorktreePlan {
-                name,
-                create_branch,
-                start_point,
-            }]
+            if start_point.is_none() {
+                tracing::warn!(
+                    %default_branch,
+                    "Default branch doesn't point to a commit; not creating a worktree for it"
+                );
+            }
+
+            start_point
+                .map(|(create_branch, start_point)| NewWorktreePlan {
+                    // Test: `convert_default_worktree_name_from_dir_new`
+                    name: default_worktree_name.clone().unwrap_or_else(|| {
+                        git.worktree()
+                            .dirname_for(default_branch.branch_name())
+                            .into_owned()
+                    }),
+                    create_branch,
+                    start_point,
+                })
+                .into_iter()
+                .collect()
         };
 
         // Tests:
@@ -304,10 +495,23 @@ where
             names: new_worktrees
                 .iter()
                 .map(|plan| plan.name.to_owned())
+                .chain(default_worktree_name.clone())
                 .collect(),
             directory_names: &FxHashSet::from_iter([destination_name]),
         })?;
 
+        if let Some(name) = &default_worktree_name {
+            // The name is reserved above, so no other worktree will have it.
+            //
+            // Test: `convert_default_worktree_name_from_dir`
+            if let Some(renamed) = worktrees
+                .values_mut()
+                .find(|renamed| renamed.worktree.head.branch() == Some(&default_branch.as_local()))
+            {
+                renamed.name = name.clone();
+            }
+        }
+
         tracing::debug!(
             "Worktree names resolved:\n{}",
             format_bulleted_list(worktrees.iter().map(|(path, worktree)| {
@@ -323,7 +527,7 @@ where
         // where we expect it!
         let worktree_plans = topological_sort(&worktrees.keys().collect::<Vec<_>>())?
             .into_iter()
-            .map(|path| {
+            .filter_map(|path| {
                 let renamed = worktrees
                     .remove(&path)
                     .expect("Topological sort will not invent worktrees");
@@ -331,23 +535,46 @@ where
                 let plan = WorktreePlan::from(renamed);
 
                 // Test: `convert_default_branch_checked_out` (and many others)
-                if plan.worktree.is_main && !plan.worktree.head.is_bare() {
+                if opts.bare && plan.worktree.is_main && !plan.worktree.head.is_bare() {
+                    let unborn = plan.worktree.head.is_unborn();
                     make_bare = Some(MainWorktreePlan {
                         inner: plan.clone(),
+                        unborn,
+                        stash: false,
                     });
+                    if unborn {
+                        // Test: `convert_unborn_head_remote`
+                        return None;
+                    }
                 }
 
-                plan
+                Some(plan)
             })
             .collect::<Vec<_>>();
 
+        if let Some(make_bare) = make_bare.as_mut().filter(|plan| !plan.unborn) {
+            // Test: `convert_uncommitted_changes`
+            make_bare.stash = git
+                .with_current_dir(&make_bare.inner.worktree.path)
+                .status()
+                .get()?
+                .staged()
+                .next()
+                .is_some();
+        }
+
         let ret = Self {
             git,
             tempdir,
             destination,
+            container,
             worktrees: worktree_plans,
             repo: repo.to_owned(),
+            git_dir_name,
             make_bare,
+            default_branch: default_branch.as_local(),
+            no_hooks: opts.no_hooks,
+            yes: opts.yes,
             new_worktrees,
         };
 
@@ -381,7 +608,7 @@ where
                 );
             }
             None => {
-                tracing::debug!("Repository is already bare");
+                tracing::debug!("Repository is already bare, or won't be made bare");
             }
         }
 
@@ -458,17 +685,69 @@ where
         }
     }
 
+    /// Ask the user before we start messing around with their repo layout.
+    ///
+    /// This is skipped with `--yes`. If stdin isn't a terminal, `--yes` is required.
+    fn confirm(&self) -> miette::Result<()> {
+        if self.yes {
+            // Test: `convert_yes`
+            return Ok(());
+        }
+
+        if !std::io::stdin().is_terminal() {
+            // Test: `convert_not_a_tty`
+            return Err(miette!(
+                "`git prole convert` needs confirmation, but stdin isn't a terminal; pass `--yes` \
+                 to convert without asking"
+            ));
+        }
+
+        eprint!("Proceed? [y/N] ");
+        let mut answer = String::new();
+        std::io::stdin()
+            .read_line(&mut answer)
+            .into_diagnostic()
+            .wrap_err("Failed to read answer")?;
+        match answer.trim().to_lowercase().as_str() {
+            "y" | "yes" => Ok(()),
+            _ => Err(miette!("Conversion cancelled")),
+        }
+    }
+
     #[instrument(level = "trace")]
     pub fn execute(&self) -> miette::Result<()> {
         tracing::info!("{self}");
 
         // Tests:
         // - `convert_no_op`
-        if self.git.config.cli.dry_run || self.is_no_op() {
+        if self.is_no_op() {
+            return Ok(());
+        }
+
+        if self.git.config.cli.dry_run {
+            self.run_commands()?;
             return Ok(());
         }
 
-        // TODO: Ask the user before we start messing around with their repo layout!
+        self.confirm()?;
+
+        // Check that we can move every worktree before we move any of them.
+        //
+        // Test: `convert_read_only_worktree`
+        for plan in &self.worktrees {
+            if !plan.worktree.head.is_bare() {
+                check_movable(&plan.worktree.path)?;
+            }
+        }
+
+        // Stash staged changes so that the `git reset` below doesn't lose them.
+        //
+        // Test: `convert_uncommitted_changes`
+        if let Some(make_bare) = self.make_bare.as_ref().filter(|plan| plan.stash) {
+            self.git
+                .with_current_dir(&make_bare.inner.worktree.path)
+                .stash_push("git-prole convert")?;
+        }
 
         // If the repository isn't already bare, separate the `.git` directory from its worktree
         // and make it bare.
@@ -476,30 +755,40 @@ where
         // Test: (for all the `make_bare` behavior)
         // - `convert_default_branch_checked_out` (and many more)
         if let Some(make_bare) = &self.make_bare {
-            fs::rename(make_bare.git_dir(), make_bare.temp_git_destination(self))?;
+            fs::rename_or_copy(make_bare.git_dir(), make_bare.temp_git_destination(self))?;
             self.git
                 .with_current_dir(make_bare.temp_git_destination(self))
                 .config()
                 .set("core.bare", "true")?;
+
+            if make_bare.unborn {
+                // The unborn main worktree is empty now; the default branch gets a new worktree
+                // instead.
+                fs::remove_dir(&make_bare.inner.worktree.path)?;
+            }
         }
 
         // Move worktrees to the tempdir.
         for plan in &self.worktrees {
-            fs::rename(&plan.worktree.path, plan.temp_destination(self))?;
+            fs::rename_or_copy(&plan.worktree.path, plan.temp_destination(self))?;
         }
 
-        // Create the destination if it doesn't exist.
-        if !self.destination.exists() {
-            fs::create_dir_all(&self.destination)?;
+        // Create the destination and container if they don't exist.
+        for directory in [&self.destination, &self.container] {
+            if !directory.exists() {
+                fs::create_dir_all(directory)?;
+            }
         }
 
         // Move the `.git` directory to its new location.
         if let Some(make_bare) = &self.make_bare {
-            fs::rename(
+            fs::rename_or_copy(
                 make_bare.temp_git_destination(self),
                 make_bare.git_destination(self),
             )?;
+        }
 
+        if let Some(make_bare) = self.make_bare.as_ref().filter(|plan| !plan.unborn) {
             // Make the main worktree into a real worktree, now that we've removed its `.git`
             // directory.
             self.git
@@ -519,7 +808,7 @@ where
             self.git
                 .with_current_dir(make_bare.inner.destination(self))
                 .reset()?;
-            fs::rename(
+            fs::rename_or_copy(
                 make_bare.worktree_git_destination(self),
                 make_bare.worktree_temp_git_destination(self),
             )?;
@@ -528,14 +817,38 @@ where
 
         // Move worktrees back from the tempdir.
         for plan in &self.worktrees {
-            fs::rename(plan.temp_destination(self), plan.destination(self))?;
+            fs::rename_or_copy(plan.temp_destination(self), plan.destination(self))?;
         }
 
         // Repair worktrees with their new paths.
-        let git = self.git.with_current_dir(self.destination.clone());
+        //
+        // If the Git directory isn't named `.git`, Git won't find it from the destination, so we
+        // run in the Git directory itself.
+        let git = self.git.with_current_dir(match &self.make_bare {
+            Some(make_bare) => make_bare.git_destination(self),
+            // Test: `convert_no_bare`
+            None => match self.non_bare_main() {
+                Some(main) => main.destination(self),
+                None => self.destination.clone(),
+            },
+        });
         git.worktree()
             .repair(self.worktrees.iter().map(|plan| plan.destination(self)))?;
 
+        if let Some(make_bare) = self.make_bare.as_ref().filter(|plan| plan.stash) {
+            // Test: `convert_uncommitted_changes`
+            self.git
+                .with_current_dir(make_bare.inner.destination(self))
+                .stash_pop()
+                .wrap_err_with(|| {
+                    format!(
+                        "Failed to restore the changes stashed before converting in {}; \
+                        they're still saved in `git stash list`",
+                        make_bare.inner.destination(self).display_path_cwd()
+                    )
+                })?;
+        }
+
         // Create new worktrees.
         for plan in &self.new_worktrees {
             git.worktree().add(
@@ -557,9 +870,80 @@ where
 
         remove_tempdir_if_empty(&self.tempdir)?;
 
+        self.run_commands()?;
+
+        Ok(())
+    }
+
+    /// The main worktree, if it keeps the Git directory because the repository isn't being made
+    /// bare.
+    fn non_bare_main(&self) -> Option<&WorktreePlan> {
+        if self.make_bare.is_some() {
+            return None;
+        }
+        self.worktrees
+            .iter()
+            .find(|plan| plan.worktree.is_main && !plan.worktree.head.is_bare())
+    }
+
+    /// The path of the default branch's worktree after conversion, if it has one.
+    fn default_worktree(&self) -> Option<Utf8PathBuf> {
+        self.worktrees
+            .iter()
+            .find(|plan| plan.worktree.head.branch() == Some(&self.default_branch))
+            .map(|plan| plan.destination(self))
+            .or_else(|| {
+                self.new_worktrees
+                    .first()
+                    .map(|plan| plan.destination(self))
+            })
+    }
+
+    /// Run the `convert.commands` in the worktree container.
+    ///
+    /// With `--dry-run`, the commands are only printed.
+    #[instrument(level = "trace")]
+    fn run_commands(&self) -> miette::Result<()> {
+        if self.no_hooks {
+            // Test: `config_convert_commands_no_hooks`
+            return Ok(());
+        }
+
+        let default_worktree = self.default_worktree();
+        for command in self.git.config.file.convert.commands() {
+            let mut command = command.as_command();
+            command
+                .current_dir(&self.container)
+                .env("GIT_PROLE_CONTAINER", &self.container);
+            if let Some(default_worktree) = &default_worktree {
+                command.env("GIT_PROLE_DEFAULT_WORKTREE", default_worktree);
+            }
+
+            tracing::info!(
+                "{} {}",
+                '$'.if_supports_color(Stream::Stdout, |text| text.green()),
+                Utf8ProgramAndArgs::from(&command)
+            );
+            if self.git.config.cli.dry_run {
+                // Test: `config_convert_commands_dry_run`
+                continue;
+            }
+
+            // Test: `config_convert_commands`
+            if let Err(error) = command.status_checked() {
+                tracing::error!("{error}");
+            }
+        }
+
         Ok(())
     }
 
+    /// Is there nothing to do?
+    ///
+    /// An already-bare repository is still converted if any of its worktrees need to be moved
+    /// or renamed.
+    ///
+    /// Test: `convert_bare_scattered_worktrees`
     pub fn is_no_op(&self) -> bool {
         self.make_bare.is_none()
             && self.new_worktrees.is_empty()
@@ -597,11 +981,18 @@ impl WorktreePlan {
     }
 
     /// Where we'll place the worktree when we're done.
+    ///
+    /// A bare main worktree is the Git directory, so it goes in the destination rather than the
+    /// container.
     fn destination(
         &self,
         convert_plan: &ConvertPlan<'_, impl AsRef<Utf8Path> + Debug>,
     ) -> Utf8PathBuf {
-        convert_plan.destination.join(&self.name)
+        if self.worktree.head.is_bare() {
+            convert_plan.destination.join(&self.name)
+        } else {
+            convert_plan.container.join(&self.name)
+        }
     }
 }
 
@@ -622,7 +1013,7 @@ impl NewWorktreePlan {
         &self,
         convert_plan: &ConvertPlan<'_, impl AsRef<Utf8Path> + Debug>,
     ) -> Utf8PathBuf {
-        convert_plan.destination.join(&self.name)
+        convert_plan.container.join(&self.name)
     }
 }
 
@@ -630,6 +1021,15 @@ impl NewWorktreePlan {
 struct MainWorktreePlan {
     /// The plan for the main worktree.
     inner: WorktreePlan,
+    /// Does the main worktree have staged changes?
+    ///
+    /// If so, changes are stashed before converting and restored afterwards, because the `git
+    /// reset` in the new worktree would lose them.
+    stash: bool,
+    /// Is the main worktree on an unborn branch?
+    ///
+    /// If so, it has no files, so we remove it instead of making it into a linked worktree.
+    unborn: bool,
 }
 
 impl MainWorktreePlan {
@@ -651,7 +1051,8 @@ impl MainWorktreePlan {
         &self,
         convert_plan: &ConvertPlan<'_, impl AsRef<Utf8Path> + Debug>,
     ) -> Utf8PathBuf {
-        convert_plan.destination.join(".git")
+        // Test: `convert_git_dir_name`
+        convert_plan.destination.join(&convert_plan.git_dir_name)
     }
 
     /// Where we'll place the _worktree's_ `.git` symlink in the temporary directory.
@@ -671,6 +1072,27 @@ impl MainWorktreePlan {
     }
 }
 
+/// Check that a worktree can be moved, i.e. that it and its parent directory are writable.
+///
+/// Moving a worktree that lives on a read-only filesystem (or in a read-only directory) would
+/// fail partway through the conversion, so we check before touching anything.
+fn check_movable(worktree: &Utf8Path) -> miette::Result<()> {
+    let parent = worktree
+        .parent()
+        .ok_or_else(|| miette!("Worktree has no parent: {worktree}"))?;
+    for directory in [worktree, parent] {
+        // This creates an anonymous temporary file, so there's nothing to clean up.
+        if let Err(error) = tempfile::tempfile_in(directory) {
+            return Err(miette!(
+                "Worktree {} can't be moved because {} isn't writable: {error}",
+                worktree.display_path_cwd(),
+                directory.display_path_cwd(),
+            ));
+        }
+    }
+    Ok(())
+}
+
 fn remove_tempdir_if_empty(tempdir: &Utf8Path) -> miette::Result<()> {
     let contents = fs::read_dir(tempdir)?.collect::<Vec<_>>();
     // From `std::fs::read_dir` documentation:
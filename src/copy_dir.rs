@@ -68,11 +68,32 @@ macro_rules! make_err {
 ///   twice.
 /// * Filesystem boundaries may be crossed.
 /// * Symbolic links will be copied, not followed.
+pub fn copy_dir<Q: AsRef<Path>, P: AsRef<Path>>(from: P, to: Q) -> Result<Vec<Error>> {
+    copy_dir_inner(from, to, true)
+}
+
+/// Like [`copy_dir`], but symbolic links are skipped instead of copied.
+pub fn copy_dir_without_symlinks<Q: AsRef<Path>, P: AsRef<Path>>(
+    from: P,
+    to: Q,
+) -> Result<Vec<Error>> {
+    copy_dir_inner(from, to, false)
+}
+
 #[instrument(level = "trace", skip_all)]
 #[expect(clippy::disallowed_methods)]
-pub fn copy_dir<Q: AsRef<Path>, P: AsRef<Path>>(from: P, to: Q) -> Result<Vec<Error>> {
+fn copy_dir_inner<Q: AsRef<Path>, P: AsRef<Path>>(
+    from: P,
+    to: Q,
+    symlinks: bool,
+) -> Result<Vec<Error>> {
     let from_meta = from.as_ref().fs_err_symlink_metadata()?;
 
+    if !symlinks && from_meta.is_symlink() {
+        tracing::trace!(from=?from.as_ref(), "Skipping symlink");
+        return Ok(Vec::new());
+    }
+
     if to.as_ref().fs_err_symlink_metadata().is_ok() {
         return Err(make_err!("target path exists", ErrorKind::AlreadyExists));
     }
@@ -152,6 +173,11 @@ pub fn copy_dir<Q: AsRef<Path>, P: AsRef<Path>>(from: P, to: Q) -> Result<Vec<Er
                 errors
             );
         } else if entry.path_is_symlink() {
+            if !symlinks {
+                tracing::trace!(from=?entry.path(), "Skipping symlink");
+                continue;
+            }
+
             // We need to get the result from the `read_link` call here, so we can't use the
             // `push_error!` macro.
             let dest = match fs::read_link(entry.path()) {
@@ -172,11 +198,9 @@ pub fn copy_dir<Q: AsRef<Path>, P: AsRef<Path>>(from: P, to: Q) -> Result<Vec<Er
                 link_dest=?dest,
                 "Copying symlink"
             );
+            // Symlinks don't have their own permissions, and setting them would follow the
+            // link to a target which may not have been copied yet.
             push_error!(fs::os::unix::fs::symlink(dest, &target_path), errors);
-            push_error!(
-                fs::set_permissions(&target_path, source_metadata.permissions()),
-                errors
-            );
         } else {
             tracing::trace!(
                 from=?entry.path(),
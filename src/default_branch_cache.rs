@@ -0,0 +1,181 @@
+//! A cache of remotes' default branches, keyed by remote URL.
+//!
+//! After asking a remote for its default branch, we store it in `refs/remotes/<remote>/HEAD` to
+//! avoid talking to the remote next time. That isn't always possible (the `.git` directory may
+//! not be writable) and doesn't help when the `.git` directory is thrown away between runs (e.g.
+//! in CI), so we also store it in the user's state directory.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use camino::Utf8PathBuf;
+use miette::Context;
+use miette::IntoDiagnostic;
+use serde::Deserialize;
+use serde::Serialize;
+use xdg::BaseDirectories;
+
+use crate::fs;
+
+/// A cache of remotes' default branches, keyed by remote URL.
+#[derive(Debug)]
+pub struct DefaultBranchCache {
+    /// The path the cache is stored at.
+    path: Utf8PathBuf,
+    file: DefaultBranchCacheFile,
+}
+
+impl DefaultBranchCache {
+    pub const FILE_NAME: &str = "default-branches.toml";
+
+    /// How long a cached default branch is used for before asking the remote again.
+    pub const TTL: Duration = Duration::from_secs(60 * 60 * 24);
+
+    /// Load the cache from the user's state directory.
+    ///
+    /// If the cache doesn't exist or can't be read, an empty cache is returned.
+    pub fn load(dirs: &BaseDirectories) -> miette::Result<Self> {
+        let path: Utf8PathBuf = dirs
+            .get_state_file(Self::FILE_NAME)
+            .try_into()
+            .into_diagnostic()?;
+
+        let file = if path.exists() {
+            match fs::read_to_string(&path).and_then(|contents| {
+                toml::from_str(&contents)
+                    .into_diagnostic()
+                    .wrap_err("Failed to deserialize default branch cache")
+            }) {
+                Ok(file) => file,
+                Err(err) => {
+                    tracing::warn!(%path, "Ignoring default branch cache: {err}");
+                    DefaultBranchCacheFile::default()
+                }
+            }
+        } else {
+            DefaultBranchCacheFile::default()
+        };
+
+        Ok(Self { path, file })
+    }
+
+    /// Get the cached default branch for the remote with the given URL, if it's not stale.
+    pub fn get(&self, url: &str) -> Option<&str> {
+        self.get_at(url, now())
+    }
+
+    fn get_at(&self, url: &str, now: u64) -> Option<&str> {
+        self.file
+            .remotes
+            .get(url)
+            .filter(|entry| now.saturating_sub(entry.updated) < Self::TTL.as_secs())
+            .map(|entry| entry.branch.as_str())
+    }
+
+    /// Record the default branch for the remote with the given URL.
+    pub fn insert(&mut self, url: &str, branch: &str) {
+        self.insert_at(url, branch, now());
+    }
+
+    fn insert_at(&mut self, url: &str, branch: &str, now: u64) {
+        self.file.remotes.insert(
+            url.to_owned(),
+            CacheEntry {
+                branch: branch.to_owned(),
+                updated: now,
+            },
+        );
+    }
+
+    /// Write the cache back to the user's state directory.
+    pub fn save(&self) -> miette::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(
+            &self.path,
+            toml::to_string(&self.file)
+                .into_diagnostic()
+                .wrap_err("Failed to serialize default branch cache")?,
+        )
+    }
+}
+
+/// The default branch cache file format.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+struct DefaultBranchCacheFile {
+    /// A map from remote URLs to their default branches.
+    remotes: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+struct CacheEntry {
+    /// The name of the default branch, without the remote name.
+    branch: String,
+    /// When this entry was written, in seconds since the Unix epoch.
+    updated: u64,
+}
+
+/// The current time, in seconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn empty_cache() -> DefaultBranchCache {
+        DefaultBranchCache {
+            path: "default-branches.toml".into(),
+            file: DefaultBranchCacheFile::default(),
+        }
+    }
+
+    #[test]
+    fn test_default_branch_cache_get() {
+        let mut cache = empty_cache();
+        cache.insert_at("https://example.com/puppy.git", "main", 1000);
+
+        assert_eq!(
+            cache.get_at("https://example.com/puppy.git", 1000),
+            Some("main")
+        );
+        assert_eq!(cache.get_at("https://example.com/doggy.git", 1000), None);
+    }
+
+    #[test]
+    fn test_default_branch_cache_ttl() {
+        let mut cache = empty_cache();
+        cache.insert_at("https://example.com/puppy.git", "main", 1000);
+
+        let ttl = DefaultBranchCache::TTL.as_secs();
+        assert_eq!(
+            cache.get_at("https://example.com/puppy.git", 1000 + ttl - 1),
+            Some("main")
+        );
+        assert_eq!(
+            cache.get_at("https://example.com/puppy.git", 1000 + ttl),
+            None
+        );
+    }
+
+    #[test]
+    fn test_default_branch_cache_file_roundtrip() {
+        let mut cache = empty_cache();
+        cache.insert_at("https://example.com/puppy.git", "main", 1000);
+
+        let serialized = toml::to_string(&cache.file).unwrap();
+        assert_eq!(
+            toml::from_str::<DefaultBranchCacheFile>(&serialized).unwrap(),
+            cache.file
+        );
+    }
+}
@@ -0,0 +1,90 @@
+//! Exit codes which scripts can rely on.
+//!
+//! See `docs/scripting.md`.
+
+use std::fmt::Display;
+
+use miette::Diagnostic;
+
+/// Why `git prole` failed, as reported by its exit code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// Any other error.
+    Failure = 1,
+    /// The command-line arguments were invalid. `clap` exits with this code itself.
+    Usage = 2,
+    /// The command needs a Git repository, and we're not in one.
+    NotARepository = 3,
+    /// A worktree had changes, so the command refused to continue.
+    DirtyWorktree = 4,
+}
+
+impl ErrorCode {
+    /// Get the exit code for an error.
+    ///
+    /// Errors which weren't created with [`ErrorCode::error`] are [`ErrorCode::Failure`].
+    pub fn of(report: &miette::Report) -> Self {
+        report
+            .chain()
+            .find_map(|error| error.downcast_ref::<CodedError>())
+            .map(|error| error.code)
+            .unwrap_or(Self::Failure)
+    }
+
+    /// Create an error with this exit code.
+    pub fn error(self, message: impl Display) -> miette::Report {
+        CodedError {
+            code: self,
+            message: message.to_string(),
+        }
+        .into()
+    }
+}
+
+impl From<ErrorCode> for std::process::ExitCode {
+    fn from(code: ErrorCode) -> Self {
+        Self::from(code as u8)
+    }
+}
+
+/// An error with a specific exit code.
+#[derive(Debug)]
+struct CodedError {
+    code: ErrorCode,
+    message: String,
+}
+
+impl Display for CodedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for CodedError {}
+
+impl Diagnostic for CodedError {}
+
+#[cfg(test)]
+mod tests {
+    use miette::miette;
+    use miette::Context;
+
+    use super::*;
+
+    #[test]
+    fn test_error_code_of() {
+        assert_eq!(ErrorCode::of(&miette!("Puppy!")), ErrorCode::Failure);
+        assert_eq!(
+            ErrorCode::of(&ErrorCode::NotARepository.error("Puppy!")),
+            ErrorCode::NotARepository
+        );
+        assert_eq!(
+            ErrorCode::of(
+                &Err::<(), _>(ErrorCode::DirtyWorktree.error("Puppy!"))
+                    .wrap_err("Doggy!")
+                    .unwrap_err()
+            ),
+            ErrorCode::DirtyWorktree
+        );
+    }
+}
@@ -2,12 +2,16 @@
 //! [`miette::Result`] instead of [`std::io::Result`].
 
 use std::fmt::Debug;
+use std::io::ErrorKind;
 use std::path::Path;
 use std::path::PathBuf;
 
+use miette::miette;
 use miette::IntoDiagnostic;
 use tracing::instrument;
 
+use crate::format_bulleted_list;
+
 #[instrument(level = "trace")]
 pub fn rename<P, Q>(from: P, to: Q) -> miette::Result<()>
 where
@@ -18,6 +22,45 @@ where
     fs_err::rename(from, to).into_diagnostic()
 }
 
+/// Like [`rename`], but if `from` and `to` are on different filesystems, `from` is copied to `to`
+/// and then removed.
+///
+/// The copy preserves symlinks and permissions, like [`crate::copy_dir::copy_dir`].
+#[instrument(level = "trace")]
+pub fn rename_or_copy<P, Q>(from: P, to: Q) -> miette::Result<()>
+where
+    P: AsRef<Path> + Debug,
+    Q: AsRef<Path> + Debug,
+{
+    #[expect(clippy::disallowed_methods)]
+    match fs_err::rename(from.as_ref(), to.as_ref()) {
+        Err(error) if error.kind() == ErrorKind::CrossesDevices => {
+            tracing::debug!(%error, "Can't rename across filesystems, copying instead");
+            copy_then_remove(from.as_ref(), to.as_ref())
+        }
+        result => result.into_diagnostic(),
+    }
+}
+
+fn copy_then_remove(from: &Path, to: &Path) -> miette::Result<()> {
+    let errors = crate::copy_dir::copy_dir(from, to).into_diagnostic()?;
+    if !errors.is_empty() {
+        // Leave `from` alone so nothing is lost.
+        return Err(miette!(
+            "Failed to copy {} to {}:\n{}",
+            from.display(),
+            to.display(),
+            format_bulleted_list(errors)
+        ));
+    }
+
+    if fs_err::symlink_metadata(from).into_diagnostic()?.is_dir() {
+        fs_err::remove_dir_all(from).into_diagnostic()
+    } else {
+        fs_err::remove_file(from).into_diagnostic()
+    }
+}
+
 #[instrument(level = "trace")]
 pub fn create_dir<P>(path: P) -> miette::Result<()>
 where
@@ -74,6 +117,15 @@ where
     fs_err::write(path, contents).into_diagnostic()
 }
 
+#[instrument(level = "trace")]
+pub fn symlink<P, Q>(original: P, link: Q) -> miette::Result<()>
+where
+    P: AsRef<Path> + Debug,
+    Q: AsRef<Path> + Debug,
+{
+    fs_err::os::unix::fs::symlink(original, link).into_diagnostic()
+}
+
 #[instrument(level = "trace")]
 pub fn read_dir<P>(path: P) -> miette::Result<fs_err::ReadDir>
 where
@@ -82,3 +134,42 @@ where
     #[expect(clippy::disallowed_methods)]
     fs_err::read_dir(path).into_diagnostic()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_copy_then_remove() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let from = tempdir.path().join("from");
+        let to = tempdir.path().join("to");
+
+        create_dir(&from).unwrap();
+        write(from.join("puppy"), "doggy").unwrap();
+        fs_err::set_permissions(from.join("puppy"), std::fs::Permissions::from_mode(0o755))
+            .unwrap();
+        symlink("puppy", from.join("link")).unwrap();
+
+        copy_then_remove(&from, &to).unwrap();
+
+        assert!(!from.exists());
+        assert_eq!(read_to_string(to.join("puppy")).unwrap(), "doggy");
+        assert_eq!(
+            fs_err::metadata(to.join("puppy"))
+                .unwrap()
+                .permissions()
+                .mode()
+                & 0o777,
+            0o755
+        );
+        assert_eq!(
+            fs_err::read_link(to.join("link")).unwrap(),
+            Path::new("puppy")
+        );
+    }
+}
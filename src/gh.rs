@@ -1,6 +1,9 @@
 use std::ops::RangeInclusive;
+use std::process::Command;
 
 use camino::Utf8Path;
+use command_error::CommandExt;
+use which::which_global;
 use winnow::combinator::eof;
 use winnow::token::take_while;
 use winnow::PResult;
@@ -31,6 +34,38 @@ pub fn parse_gh_url(input: &mut &str) -> PResult<()> {
     Ok(())
 }
 
+/// Get the title of a GitHub issue in the current repository with `gh`.
+///
+/// Returns `None` if `gh` isn't installed or the title can't be fetched.
+pub fn issue_title(number: u64) -> Option<String> {
+    if which_global("gh").is_err() {
+        tracing::debug!("`gh` isn't installed; not fetching issue title");
+        return None;
+    }
+
+    match Command::new("gh")
+        .args(["issue", "view", &number.to_string(), "--json", "title"])
+        .args(["--jq", ".title"])
+        .output_checked_utf8()
+    {
+        Ok(output) => Some(output.stdout.trim().to_owned()).filter(|title| !title.is_empty()),
+        Err(err) => {
+            tracing::debug!("Failed to fetch issue title: {err}");
+            None
+        }
+    }
+}
+
+/// Convert a title into a lowercase slug suitable for a branch name, like `fix-the-bug`.
+pub fn slugify(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,4 +93,11 @@ mod tests {
             "a".repeat(101)
         )));
     }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Fix the puppy's bed!"), "fix-the-puppy-s-bed");
+        assert_eq!(slugify("  `add --issue` support "), "add-issue-support");
+        assert_eq!(slugify("!!!"), "");
+    }
 }
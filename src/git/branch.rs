@@ -1,14 +1,12 @@
 use std::fmt::Debug;
+use std::process::Command;
 
-use camino::Utf8Path;
 use command_error::CommandExt;
 use command_error::OutputContext;
 use rustc_hash::FxHashSet;
 use tracing::instrument;
 use utf8_command::Utf8Output;
 
-use crate::AppGit;
-
 use super::BranchRef;
 use super::GitLike;
 use super::LocalBranchRef;
@@ -71,9 +69,25 @@ where
     }
 
     /// Does the given branch name exist as a local branch, a unique remote branch, or neither?
+    ///
+    /// If the branch exists both locally and on a remote, the local branch is preferred.
     pub fn local_or_remote(&self, branch: &str) -> miette::Result<Option<BranchRef>> {
         if self.exists_local(branch)? {
-            Ok(Some(LocalBranchRef::new(branch.to_owned()).into()))
+            let local = LocalBranchRef::new(branch.to_owned());
+            if let Some(remote) = self.0.remote().for_branch(branch)? {
+                let local_commit = self.0.refs().parse(&format!("{local:#}"))?;
+                let remote_commit = self.0.refs().parse(&format!("{remote:#}"))?;
+                if local_commit != remote_commit {
+                    // Test: `add_by_name_local_and_remote`
+                    tracing::warn!(
+                        "Local branch {local} and remote branch {remote} point to different \
+                         commits; using the local branch"
+                    );
+                } else {
+                    tracing::debug!(%local, %remote, "Branch exists locally and on a remote; using the local branch");
+                }
+            }
+            Ok(Some(local.into()))
         } else if let Some(remote) = self.0.remote().for_branch(branch)? {
             // This is the implicit behavior documented in `git-worktree(1)`.
             Ok(Some(remote.into()))
@@ -89,6 +103,26 @@ where
         }
     }
 
+    /// `git branch --delete`, which refuses to delete branches that aren't merged.
+    #[instrument(level = "trace")]
+    pub fn delete_command(&self, branch: &LocalBranchRef) -> Command {
+        let mut command = self.0.command();
+        command.args(["branch", "--delete", branch.branch_name()]);
+        command
+    }
+
+    /// Rename a local branch with `git branch --move`.
+    ///
+    /// Worktrees with the branch checked out follow the rename.
+    #[instrument(level = "trace")]
+    pub fn rename(&self, from: &LocalBranchRef, to: &LocalBranchRef) -> miette::Result<()> {
+        self.0
+            .command()
+            .args(["branch", "--move", from.branch_name(), to.branch_name()])
+            .output_checked_utf8()?;
+        Ok(())
+    }
+
     /// Get the branch that a given branch is tracking.
     pub fn upstream(&self, branch: &str) -> miette::Result<Option<BranchRef>> {
         match self
@@ -102,36 +136,3 @@ where
         }
     }
 }
-
-impl<'a, C> GitBranch<'a, AppGit<'a, C>>
-where
-    C: AsRef<Utf8Path>,
-{
-    /// Get the user's preferred default branch.
-    #[instrument(level = "trace")]
-    pub fn preferred(&self) -> miette::Result<Option<BranchRef>> {
-        if let Some(default_remote) = self.0.remote().preferred()? {
-            return self
-                .0
-                .remote()
-                .default_branch(&default_remote)
-                .map(BranchRef::from)
-                .map(Some);
-        }
-
-        let preferred_branches = self.0.config.file.branch_names();
-        let all_branches = self.0.branch().list_local()?;
-        for preferred_branch in preferred_branches {
-            let preferred_branch = LocalBranchRef::new(preferred_branch);
-            if all_branches.contains(&preferred_branch) {
-                return Ok(Some(preferred_branch.into()));
-            } else if let Some(remote_branch) =
-                self.0.remote().for_branch(preferred_branch.branch_name())?
-            {
-                return Ok(Some(remote_branch.into()));
-            }
-        }
-
-        Ok(None)
-    }
-}
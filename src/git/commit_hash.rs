@@ -1,5 +1,7 @@
 use std::fmt::Display;
 use std::str::FromStr;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 
 use derive_more::{AsRef, Constructor, Deref, DerefMut, From, Into};
 use miette::miette;
@@ -8,6 +10,9 @@ use winnow::token::one_of;
 use winnow::PResult;
 use winnow::Parser;
 
+/// The length of abbreviated commit hashes; see [`CommitHash::set_abbrev_len`].
+static ABBREV_LEN: AtomicUsize = AtomicUsize::new(CommitHash::DEFAULT_ABBREV_LEN);
+
 /// A Git commit hash.
 #[derive(
     Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Into, AsRef, Deref, DerefMut, Constructor,
@@ -15,20 +20,54 @@ use winnow::Parser;
 pub struct CommitHash(String);
 
 impl CommitHash {
+    /// The default length of abbreviated commit hashes.
+    pub const DEFAULT_ABBREV_LEN: usize = 8;
+
+    /// The shortest abbreviation length Git allows for `core.abbrev`.
+    pub const MIN_ABBREV_LEN: usize = 4;
+
+    /// The length of a full commit hash.
+    const LEN: usize = 40;
+
     /// A fake commit hash for testing purposes.
     #[cfg(test)]
     pub fn fake() -> Self {
         Self("a".repeat(40))
     }
 
-    /// Get an abbreviated 8-character Git hash.
+    /// Is this the all-zeros null commit hash?
+    ///
+    /// Git uses this to indicate a missing commit, e.g. for a worktree on an unborn branch in a
+    /// repository with no commits yet.
+    pub fn is_null(&self) -> bool {
+        self.0.bytes().all(|byte| byte == b'0')
+    }
+
+    /// Get an abbreviated Git hash.
+    ///
+    /// This is 8 characters long by default; see [`CommitHash::set_abbrev_len`].
     pub fn abbrev(&self) -> &str {
-        &self.0[..8]
+        &self.0[..ABBREV_LEN.load(Ordering::Relaxed).min(self.0.len())]
+    }
+
+    /// Set the length of abbreviated commit hashes for the rest of the program.
+    ///
+    /// Lengths outside of the range Git allows are clamped with a warning.
+    pub fn set_abbrev_len(len: usize) {
+        let clamped = len.clamp(Self::MIN_ABBREV_LEN, Self::LEN);
+        if clamped != len {
+            tracing::warn!(
+                "Commit hash abbreviation length must be between {} and {}; using {clamped} instead of {len}",
+                Self::MIN_ABBREV_LEN,
+                Self::LEN,
+            );
+        }
+        ABBREV_LEN.store(clamped, Ordering::Relaxed);
     }
 
     pub fn parser(input: &mut &str) -> PResult<Self> {
         Ok(Self::from(
-            repeat(40, one_of(('0'..='9', 'a'..='f')))
+            repeat(Self::LEN, one_of(('0'..='9', 'a'..='f')))
                 .map(|()| ())
                 .take()
                 .parse_next(input)?,
@@ -86,4 +125,10 @@ mod tests {
         // Illegal character
         assert!(CommitHash::from_str("1233def1234def1234gef1234def1234def1234b").is_err());
     }
+
+    #[test]
+    fn test_commit_hash_is_null() {
+        assert!(CommitHash::from_str(&"0".repeat(40)).unwrap().is_null());
+        assert!(!CommitHash::fake().is_null());
+    }
 }
@@ -1,8 +1,10 @@
 use std::fmt::Debug;
 
+use camino::Utf8PathBuf;
 use command_error::CommandExt;
 use command_error::OutputContext;
 use miette::miette;
+use miette::IntoDiagnostic;
 use tracing::instrument;
 use utf8_command::Utf8Output;
 
@@ -72,26 +74,38 @@ where
         self.get_and(key, |_, value| Ok(value))
     }
 
-    /// Check if this repository is bare.
+    /// Get a boolean config setting by name.
+    ///
+    /// This accepts the same spellings as Git; see [`parse_bool`].
     #[instrument(level = "trace")]
-    pub fn is_bare(&self) -> miette::Result<bool> {
-        self.get_and("core.bare", |context, value| {
-            match value {
-                None => {
-                    // This seems to not happen in practice, but whatever.
-                    Ok(false)
+    pub fn get_bool(&self, key: &str) -> miette::Result<Option<bool>> {
+        self.get_and(key, |context, value| match value {
+            None => Ok(None),
+            Some(value) => {
+                match parse_bool(&value) {
+                    Some(value) => Ok(Some(value)),
+                    None => Err(context
+                        .error_msg(miette!("Unexpected Git config value for `{key}`: {value}"))),
                 }
-                Some(value) => match value.as_str() {
-                    "true" => Ok(true),
-                    "false" => Ok(false),
-                    _ => Err(context.error_msg(miette!(
-                        "Unexpected Git config value for `core.bare`: {value}"
-                    ))),
-                },
             }
         })
     }
 
+    /// Get a path config setting by name.
+    ///
+    /// A leading `~` is expanded to the user's home directory, like Git does.
+    #[instrument(level = "trace")]
+    pub fn get_path(&self, key: &str) -> miette::Result<Option<Utf8PathBuf>> {
+        self.get(key)?.map(|value| expand_home(&value)).transpose()
+    }
+
+    /// Check if this repository is bare.
+    #[instrument(level = "trace")]
+    pub fn is_bare(&self) -> miette::Result<bool> {
+        // If unset: This seems to not happen in practice, but whatever.
+        Ok(self.get_bool("core.bare")?.unwrap_or(false))
+    }
+
     /// Set a local config setting.
     #[instrument(level = "trace")]
     pub fn set(&self, key: &str, value: &str) -> miette::Result<()> {
@@ -101,4 +115,81 @@ where
             .output_checked_utf8()?;
         Ok(())
     }
+
+    /// Set a config setting for the current worktree only.
+    ///
+    /// This enables `extensions.worktreeConfig` for the repository, which per-worktree settings
+    /// require.
+    #[instrument(level = "trace")]
+    pub fn set_worktree(&self, key: &str, value: &str) -> miette::Result<()> {
+        self.set("extensions.worktreeConfig", "true")?;
+        self.0
+            .command()
+            .args(["config", "set", "--worktree", key, value])
+            .output_checked_utf8()?;
+        Ok(())
+    }
+}
+
+/// Parse a Git boolean config value.
+///
+/// Like Git, `true`, `yes`, `on`, and `1` are true, and `false`, `no`, `off`, `0`, and the empty
+/// string are false. Case is ignored.
+///
+/// See: `man 'git-config(1)'`
+pub fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "yes" | "on" | "1" => Some(true),
+        "false" | "no" | "off" | "0" | "" => Some(false),
+        _ => None,
+    }
+}
+
+/// Expand a leading `~` in a path config value to the user's home directory.
+pub(crate) fn expand_home(value: &str) -> miette::Result<Utf8PathBuf> {
+    let rest = match value.strip_prefix('~') {
+        Some("") => "",
+        Some(rest) if rest.starts_with('/') => &rest[1..],
+        _ => return Ok(value.into()),
+    };
+    let home = dirs::home_dir().ok_or_else(|| miette!("Could not find home directory"))?;
+    let home = Utf8PathBuf::try_from(home).into_diagnostic()?;
+    Ok(if rest.is_empty() {
+        home
+    } else {
+        home.join(rest)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_bool() {
+        for value in ["true", "TRUE", "True", "yes", "on", "1"] {
+            assert_eq!(parse_bool(value), Some(true), "{value:?}");
+        }
+        for value in ["false", "FALSE", "no", "Off", "0", ""] {
+            assert_eq!(parse_bool(value), Some(false), "{value:?}");
+        }
+        for value in ["puppy", "2", "-1", "truee"] {
+            assert_eq!(parse_bool(value), None, "{value:?}");
+        }
+    }
+
+    #[test]
+    fn test_expand_home() {
+        let home = Utf8PathBuf::try_from(dirs::home_dir().unwrap()).unwrap();
+        assert_eq!(expand_home("~").unwrap(), home);
+        assert_eq!(expand_home("~/hooks").unwrap(), home.join("hooks"));
+        assert_eq!(expand_home("hooks").unwrap(), Utf8PathBuf::from("hooks"));
+        assert_eq!(expand_home("/hooks").unwrap(), Utf8PathBuf::from("/hooks"));
+        assert_eq!(
+            expand_home("~puppy/hooks").unwrap(),
+            Utf8PathBuf::from("~puppy/hooks")
+        );
+    }
 }
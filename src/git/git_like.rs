@@ -8,6 +8,7 @@ use super::GitConfig;
 use super::GitPath;
 use super::GitRefs;
 use super::GitRemote;
+use super::GitSparseCheckout;
 use super::GitStatus;
 use super::GitWorktree;
 
@@ -68,4 +69,10 @@ pub trait GitLike: Sized {
     fn branch(&self) -> GitBranch<'_, Self> {
         GitBranch::new(self)
     }
+
+    /// Methods for dealing with `git sparse-checkout`.
+    #[inline]
+    fn sparse_checkout(&self) -> GitSparseCheckout<'_, Self> {
+        GitSparseCheckout::new(self)
+    }
 }
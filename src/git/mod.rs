@@ -16,12 +16,16 @@ mod path;
 mod refs;
 mod remote;
 mod repository_url_destination;
+mod sparse_checkout;
 mod status;
+mod status_v2;
+mod url_branch;
 mod worktree;
 
 pub use branch::GitBranch;
 pub use commit_hash::CommitHash;
 pub use commitish::ResolvedCommitish;
+pub(crate) use config::expand_home;
 pub use config::GitConfig;
 pub use git_like::GitLike;
 pub use head_state::HeadKind;
@@ -33,10 +37,17 @@ pub use refs::Ref;
 pub use refs::RemoteBranchRef;
 pub use remote::GitRemote;
 pub use repository_url_destination::repository_url_destination;
+pub use sparse_checkout::GitSparseCheckout;
+pub use sparse_checkout::SparseCheckout;
 pub use status::GitStatus;
 pub use status::Status;
 pub use status::StatusCode;
 pub use status::StatusEntry;
+pub use status_v2::BranchStatus;
+pub use status_v2::StatusV2;
+pub use status_v2::StatusV2Entry;
+pub use status_v2::SubmoduleStatus;
+pub use url_branch::UrlBranch;
 pub use worktree::AddWorktreeOpts;
 pub use worktree::GitWorktree;
 pub use worktree::RenamedWorktree;
@@ -46,6 +57,7 @@ pub use worktree::WorktreeHead;
 pub use worktree::Worktrees;
 
 use crate::app_git::AppGit;
+use crate::cli::Verbosity;
 use crate::config::Config;
 use crate::current_dir::current_dir_utf8;
 
@@ -164,15 +176,27 @@ where
         command
     }
 
+    /// `git clone`, passing `--quiet` or `--progress` as `verbosity` requires.
     #[instrument(level = "trace")]
     pub fn clone_repository(
         &self,
         repository: &str,
         destination: Option<&Utf8Path>,
+        verbosity: Verbosity,
         args: &[String],
     ) -> miette::Result<()> {
         let mut command = self.command();
-        command.arg("clone").args(args).arg(repository);
+        command.arg("clone");
+        match verbosity {
+            Verbosity::Quiet => {
+                command.arg("--quiet");
+            }
+            Verbosity::Normal => {}
+            Verbosity::Verbose => {
+                command.arg("--progress");
+            }
+        }
+        command.args(args).arg(repository);
         if let Some(destination) = destination {
             command.arg(destination);
         }
@@ -186,4 +210,47 @@ where
         self.command().arg("reset").output_checked_utf8()?;
         Ok(())
     }
+
+    /// `git stash push`, stashing staged and unstaged changes to tracked files.
+    #[instrument(level = "trace")]
+    pub fn stash_push(&self, message: &str) -> miette::Result<()> {
+        self.command()
+            .args(["stash", "push", "--message", message])
+            .output_checked_utf8()?;
+        Ok(())
+    }
+
+    /// `git stash pop --index`, restoring the most recent stash and which of its changes were
+    /// staged.
+    #[instrument(level = "trace")]
+    pub fn stash_pop(&self) -> miette::Result<()> {
+        self.command()
+            .args(["stash", "pop", "--index"])
+            .output_checked_utf8()?;
+        Ok(())
+    }
+
+    /// Check if the index is empty, like in a worktree created with `git worktree add
+    /// --no-checkout`.
+    #[instrument(level = "trace")]
+    pub fn index_is_empty(&self) -> miette::Result<bool> {
+        Ok(self
+            .command()
+            .arg("ls-files")
+            .output_checked_utf8()?
+            .stdout
+            .is_empty())
+    }
+
+    /// Read `HEAD` into the index and check out every file in it.
+    #[instrument(level = "trace")]
+    pub fn checkout_head(&self) -> miette::Result<()> {
+        self.command()
+            .args(["read-tree", "HEAD"])
+            .output_checked_utf8()?;
+        self.command()
+            .args(["checkout-index", "--all"])
+            .output_checked_utf8()?;
+        Ok(())
+    }
 }
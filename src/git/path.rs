@@ -2,9 +2,13 @@ use std::fmt::Debug;
 
 use camino::Utf8PathBuf;
 use command_error::CommandExt;
+use command_error::OutputContext;
 use miette::miette;
+use miette::IntoDiagnostic;
 use tracing::instrument;
+use utf8_command::Utf8Output;
 
+use crate::format_bulleted_list;
 use crate::PathDisplay;
 
 use super::GitLike;
@@ -66,6 +70,68 @@ where
         }
     }
 
+    /// Check if we're inside a Git repository, either in a worktree or a `.git` directory.
+    #[instrument(level = "trace")]
+    pub fn is_inside_repository(&self) -> miette::Result<bool> {
+        Ok(self
+            .0
+            .as_git()
+            .rev_parse_command()
+            .arg("--git-dir")
+            .output_checked_as(|context: OutputContext<Utf8Output>| {
+                Ok::<_, command_error::Error>(context.status().success())
+            })?)
+    }
+
+    /// Find a directory to run Git commands for the repository from.
+    ///
+    /// Inside a worktree or a `.git` directory, this is the current directory. Otherwise, if the
+    /// current directory is a worktree container whose bare repository has a name Git doesn't
+    /// find on its own (like `.bare`), this is that bare repository.
+    ///
+    /// Returns `None` if no repository is found.
+    #[instrument(level = "trace")]
+    pub fn repository_dir(&self) -> miette::Result<Option<Utf8PathBuf>> {
+        let current_dir = self.0.get_current_dir().as_ref();
+        if self.is_inside_repository()? {
+            // Unrelated repositories nested in a worktree are ignored.
+            //
+            // Test: `list_nested_bare_repo`
+            return Ok(Some(current_dir.to_owned()));
+        }
+
+        let mut found = Vec::new();
+        for entry in current_dir.read_dir_utf8().into_diagnostic()? {
+            let path = entry.into_diagnostic()?.into_path();
+            // Cheap check before asking Git; worktrees don't have a `HEAD` file at the top.
+            if !path.join("HEAD").is_file() {
+                continue;
+            }
+            let git = self.0.as_git().with_current_dir(path.as_path());
+            if !git.path().is_inside_repository()? {
+                continue;
+            }
+            let common_dir = path.join(git.path().git_common_dir()?);
+            if common_dir.canonicalize_utf8().ok() == path.canonicalize_utf8().ok() {
+                found.push(path);
+            }
+        }
+
+        match found.len() {
+            0 => Ok(None),
+            1 => {
+                let git_dir = found.pop().expect("Length checked above");
+                tracing::debug!(%git_dir, "Found bare repository in worktree container");
+                Ok(Some(git_dir))
+            }
+            _ => Err(miette!(
+                "Found multiple Git repositories in {}; use `-C` to choose one:\n{}",
+                current_dir.display_path_cwd(),
+                format_bulleted_list(found.iter().map(|path| path.display_path_cwd()))
+            )),
+        }
+    }
+
     /// Get the `.git` directory path.
     #[expect(dead_code)] // #[instrument(level = "trace")]
     pub(crate) fn get_git_dir(&self) -> miette::Result<Utf8PathBuf> {
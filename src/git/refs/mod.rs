@@ -58,6 +58,20 @@ where
             .stdout)
     }
 
+    /// Get the subject (the first line of the message) of the commit at `commitish`.
+    #[instrument(level = "trace")]
+    pub fn subject(&self, commitish: &str) -> miette::Result<String> {
+        Ok(self
+            .0
+            .command()
+            .args(["log", "-1", "--format=%s", "--end-of-options", commitish])
+            .output_checked_utf8()
+            .wrap_err("Failed to get commit subject")?
+            .stdout
+            .trim_end()
+            .to_owned())
+    }
+
     /// Get the `HEAD` commit hash.
     #[instrument(level = "trace")]
     pub fn get_head(&self) -> miette::Result<CommitHash> {
@@ -163,10 +177,21 @@ where
 
     #[instrument(level = "trace")]
     pub fn for_each_ref(&self, globs: Option<&[&str]>) -> miette::Result<Vec<Ref>> {
+        self.for_each_ref_sorted(None, globs)
+    }
+
+    /// List refs, sorted by the given `git for-each-ref --sort` key, like `-committerdate`.
+    #[instrument(level = "trace")]
+    pub fn for_each_ref_sorted(
+        &self,
+        sort: Option<&str>,
+        globs: Option<&[&str]>,
+    ) -> miette::Result<Vec<Ref>> {
         self.0
             .command()
             .args(["for-each-ref", "--format=%(refname)"])
             .tap_mut(|c| {
+                sort.map(|sort| c.arg(format!("--sort={sort}")));
                 globs.map(|globs| c.args(globs));
             })
             .output_checked_utf8()?
@@ -63,8 +63,7 @@ impl Ref {
     }
 
     /// Determine if this is a tag, i.e. its kind is [`Self::TAGS`].
-    #[expect(dead_code)]
-    pub(crate) fn is_tag(&self) -> bool {
+    pub fn is_tag(&self) -> bool {
         self.kind == Self::TAGS
     }
 
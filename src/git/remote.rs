@@ -15,6 +15,7 @@ use winnow::token::take_till;
 use winnow::PResult;
 use winnow::Parser;
 
+use crate::default_branch_cache::DefaultBranchCache;
 use crate::AppGit;
 
 use super::GitLike;
@@ -60,6 +61,20 @@ where
             .collect())
     }
 
+    /// Get the (fetch) URL for the given remote.
+    #[instrument(level = "trace")]
+    pub fn get_url(&self, remote: &str) -> miette::Result<String> {
+        Ok(self
+            .0
+            .command()
+            .args(["remote", "get-url", remote])
+            .output_checked_utf8()
+            .wrap_err("Failed to get Git remote URL")?
+            .stdout
+            .trim()
+            .to_owned())
+    }
+
     /// Get the (push) URL for the given remote.
     #[expect(dead_code)] // #[instrument(level = "trace")]
     pub(crate) fn get_push_url(&self, remote: &str) -> miette::Result<String> {
@@ -121,7 +136,8 @@ where
             })?;
 
         // To avoid talking to the remote next time, write a symbolic-ref.
-        self.0
+        if let Err(err) = self
+            .0
             .command()
             .args([
                 "symbolic-ref",
@@ -129,9 +145,11 @@ where
                 &format!("refs/remotes/{remote}/{branch}"),
             ])
             .output_checked_utf8()
-            .wrap_err_with(|| {
-                format!("Failed to store symbolic ref for default branch for remote {remote}")
-            })?;
+        {
+            tracing::warn!(
+                "Failed to store symbolic ref for default branch for remote {remote}: {err}"
+            );
+        }
 
         Ok(branch)
     }
@@ -145,6 +163,25 @@ where
         })
     }
 
+    /// Set `refs/remotes/<remote>/HEAD` to the remote's default branch if it isn't set already.
+    ///
+    /// Returns the remote's default branch.
+    #[instrument(level = "trace")]
+    pub fn set_head(&self, remote: &str) -> miette::Result<RemoteBranchRef> {
+        if let Ok(branch) = self.default_branch_symbolic_ref(remote) {
+            tracing::debug!(%branch, "`{remote}/HEAD` is already set");
+            return Ok(branch);
+        }
+
+        self.0
+            .command()
+            .args(["remote", "set-head", remote, "--auto"])
+            .output_checked_utf8()
+            .wrap_err_with(|| format!("Failed to set `{remote}/HEAD`"))?;
+
+        self.default_branch_symbolic_ref(remote)
+    }
+
     /// Get the `checkout.defaultRemote` setting.
     #[instrument(level = "trace")]
     pub fn get_default(&self) -> miette::Result<Option<String>> {
@@ -189,6 +226,23 @@ where
         }
     }
 
+    /// Does the given remote have the given branch?
+    ///
+    /// Unlike checking for a remote-tracking branch, this asks the remote, so it works for
+    /// branches which haven't been fetched yet.
+    #[instrument(level = "trace")]
+    pub fn has_branch(&self, remote: &str, branch: &LocalBranchRef) -> miette::Result<bool> {
+        Ok(!self
+            .0
+            .command()
+            .args(["ls-remote", "--heads", remote, &format!("{branch:#}")])
+            .output_checked_utf8()
+            .wrap_err_with(|| format!("Failed to list branches on remote {remote}"))?
+            .stdout
+            .trim()
+            .is_empty())
+    }
+
     /// Fetch a refspec from a remote.
     #[instrument(level = "trace")]
     pub fn fetch(&self, remote: &str, refspec: Option<&str>) -> miette::Result<()> {
@@ -229,6 +283,34 @@ where
         Ok(sorted)
     }
 
+    /// Get the default branch for the given remote, checking the user's [`DefaultBranchCache`]
+    /// before asking the remote.
+    #[instrument(level = "trace")]
+    pub fn default_branch_cached(&self, remote: &str) -> miette::Result<RemoteBranchRef> {
+        match self.default_branch_symbolic_ref(remote) {
+            Ok(branch) => {
+                return Ok(branch);
+            }
+            Err(err) => {
+                tracing::debug!("Failed to get default branch: {err}");
+            }
+        }
+
+        let url = self.get_url(remote)?;
+        let mut cache = DefaultBranchCache::load(&self.0.config.dirs)?;
+        if let Some(branch) = cache.get(&url) {
+            tracing::debug!(%remote, %url, %branch, "Using cached default branch");
+            return Ok(RemoteBranchRef::new(remote, branch));
+        }
+
+        let branch = self.default_branch_ls_remote(remote)?;
+        cache.insert(&url, branch.branch_name());
+        if let Err(err) = cache.save() {
+            tracing::warn!("Failed to write default branch cache: {err}");
+        }
+        Ok(branch)
+    }
+
     /// Get the user's preferred remote, if any.
     #[instrument(level = "trace")]
     pub fn preferred(&self) -> miette::Result<Option<String>> {
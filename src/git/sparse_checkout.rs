@@ -0,0 +1,92 @@
+use std::fmt::Debug;
+use std::fmt::Display;
+
+use command_error::CommandExt;
+use tracing::instrument;
+
+use super::GitLike;
+
+/// Git methods for dealing with `git sparse-checkout`.
+#[repr(transparent)]
+pub struct GitSparseCheckout<'a, G>(&'a G);
+
+impl<G> Debug for GitSparseCheckout<'_, G>
+where
+    G: GitLike,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("GitSparseCheckout")
+            .field(&self.0.get_current_dir().as_ref())
+            .finish()
+    }
+}
+
+impl<'a, G> GitSparseCheckout<'a, G>
+where
+    G: GitLike,
+{
+    pub fn new(git: &'a G) -> Self {
+        Self(git)
+    }
+
+    /// Get the current worktree's sparse-checkout patterns, or `None` if sparse-checkout isn't
+    /// enabled.
+    #[instrument(level = "trace")]
+    pub fn get(&self) -> miette::Result<Option<SparseCheckout>> {
+        let config = self.0.config();
+        if !config.get_bool("core.sparseCheckout")?.unwrap_or(false) {
+            return Ok(None);
+        }
+        let cone = config.get_bool("core.sparseCheckoutCone")?.unwrap_or(false);
+
+        let patterns = self
+            .0
+            .command()
+            .args(["sparse-checkout", "list"])
+            .output_checked_utf8()?
+            .stdout
+            .lines()
+            .map(ToOwned::to_owned)
+            .collect();
+
+        Ok(Some(SparseCheckout { cone, patterns }))
+    }
+
+    /// Enable sparse-checkout in the current worktree with the given patterns.
+    #[instrument(level = "trace")]
+    pub fn set(&self, sparse_checkout: &SparseCheckout) -> miette::Result<()> {
+        self.0
+            .command()
+            .args(["sparse-checkout", "set"])
+            .arg(if sparse_checkout.cone {
+                "--cone"
+            } else {
+                "--no-cone"
+            })
+            .args(&sparse_checkout.patterns)
+            .output_checked_utf8()?;
+        Ok(())
+    }
+}
+
+/// A worktree's sparse-checkout settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseCheckout {
+    /// Whether cone mode is enabled, in which case `patterns` are directories.
+    ///
+    /// See: `man 'git-sparse-checkout(1)'`
+    pub cone: bool,
+    /// The directories (in cone mode) or `.gitignore`-style patterns to check out.
+    pub patterns: Vec<String>,
+}
+
+impl Display for SparseCheckout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            if self.cone { "directories" } else { "patterns" },
+            self.patterns.join(", ")
+        )
+    }
+}
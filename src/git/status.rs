@@ -20,6 +20,7 @@ use winnow::Parser;
 use crate::parse::till_null;
 
 use super::GitLike;
+use super::StatusV2;
 
 /// Git methods for dealing with statuses and the working tree.
 #[repr(transparent)]
@@ -58,6 +59,30 @@ where
                 }
             })?)
     }
+
+    /// Get the status with `--porcelain=v2 --branch`, which includes the current branch, its
+    /// upstream, and how far ahead/behind of the upstream it is.
+    #[instrument(level = "trace")]
+    pub fn get_v2(&self) -> miette::Result<StatusV2> {
+        Ok(self
+            .0
+            .command()
+            .args([
+                "status",
+                "--porcelain=v2",
+                "--branch",
+                "--ignored=traditional",
+                "-z",
+            ])
+            .output_checked_as(|context: OutputContext<Utf8Output>| {
+                if context.status().success() {
+                    StatusV2::from_str(&context.output().stdout)
+                        .map_err(|err| context.error_msg(err))
+                } else {
+                    Err(context.error())
+                }
+            })?)
+    }
 }
 
 /// The status code of a particular file. Each [`StatusEntry`] has two of these.
@@ -147,8 +172,10 @@ pub struct StatusEntry {
     /// merge.
     pub right: StatusCode,
     /// The path for this status entry.
+    ///
+    /// For renamed and copied entries, this is the destination path.
     pub path: Utf8PathBuf,
-    /// The path this status entry was renamed from, if any.
+    /// The path this status entry was renamed or copied from, if any.
     pub renamed_from: Option<Utf8PathBuf>,
 }
 
@@ -161,6 +188,10 @@ impl StatusEntry {
         self.codes().any(|code| matches!(code, StatusCode::Renamed))
     }
 
+    pub fn is_copied(&self) -> bool {
+        self.codes().any(|code| matches!(code, StatusCode::Copied))
+    }
+
     /// True if the file is not ignored, untracked, or unmodified.
     pub fn is_modified(&self) -> bool {
         self.codes().any(|code| {
@@ -175,6 +206,19 @@ impl StatusEntry {
         self.codes().any(|code| matches!(code, StatusCode::Ignored))
     }
 
+    pub fn is_untracked(&self) -> bool {
+        self.codes()
+            .any(|code| matches!(code, StatusCode::Untracked))
+    }
+
+    /// True if the file has changes in the index.
+    pub fn is_staged(&self) -> bool {
+        !matches!(
+            self.left,
+            StatusCode::Ignored | StatusCode::Untracked | StatusCode::Unmodified
+        )
+    }
+
     pub fn parser(input: &mut &str) -> PResult<Self> {
         let left = StatusCode::parser.parse_next(input)?;
         let right = StatusCode::parser.parse_next(input)?;
@@ -188,7 +232,8 @@ impl StatusEntry {
             renamed_from: None,
         };
 
-        if entry.is_renamed() {
+        // Renamed and copied entries are followed by the source path.
+        if entry.is_renamed() || entry.is_copied() {
             let renamed_from = till_null.parse_next(input)?;
             entry.renamed_from = Some(Utf8PathBuf::from(renamed_from));
         }
@@ -205,6 +250,7 @@ impl FromStr for StatusEntry {
     }
 }
 
+/// Formats like `git status --porcelain` without `-z`, e.g. `R  README.md -> PUPPY.md`.
 impl Display for StatusEntry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}{} ", self.left, self.right)?;
@@ -257,6 +303,26 @@ impl Status {
     pub fn iter(&self) -> std::slice::Iter<'_, StatusEntry> {
         self.entries.iter()
     }
+
+    /// Entries for ignored files. See [`StatusEntry::is_ignored`].
+    pub fn ignored(&self) -> impl Iterator<Item = &StatusEntry> {
+        self.iter().filter(|entry| entry.is_ignored())
+    }
+
+    /// Entries for untracked files. See [`StatusEntry::is_untracked`].
+    pub fn untracked(&self) -> impl Iterator<Item = &StatusEntry> {
+        self.iter().filter(|entry| entry.is_untracked())
+    }
+
+    /// Entries for modified files. See [`StatusEntry::is_modified`].
+    pub fn modified(&self) -> impl Iterator<Item = &StatusEntry> {
+        self.iter().filter(|entry| entry.is_modified())
+    }
+
+    /// Entries for files with staged changes. See [`StatusEntry::is_staged`].
+    pub fn staged(&self) -> impl Iterator<Item = &StatusEntry> {
+        self.iter().filter(|entry| entry.is_staged())
+    }
 }
 
 impl IntoIterator for Status {
@@ -292,6 +358,27 @@ mod tests {
 
     use super::*;
 
+    const COMPLEX_STATUS: &str = indoc!(
+        " M Cargo.lock
+         M Cargo.toml
+         M src/app.rs
+         M src/cli.rs
+         D src/commit_hash.rs
+         D src/git.rs
+         M src/main.rs
+         D src/ref_name.rs
+         D src/worktree.rs
+        ?? src/config.rs
+        ?? src/git/
+        ?? src/utf8tempdir.rs
+        !! target/
+        "
+    );
+
+    fn paths<'a>(entries: impl Iterator<Item = &'a StatusEntry>) -> Vec<&'a str> {
+        entries.map(|entry| entry.path.as_str()).collect()
+    }
+
     #[test]
     fn test_status_parse_empty() {
         assert_eq!(Status::from_str("").unwrap().entries, vec![]);
@@ -300,27 +387,9 @@ mod tests {
     #[test]
     fn test_status_parse_complex() {
         assert_eq!(
-            Status::from_str(
-                &indoc!(
-                    " M Cargo.lock
-                     M Cargo.toml
-                     M src/app.rs
-                     M src/cli.rs
-                     D src/commit_hash.rs
-                     D src/git.rs
-                     M src/main.rs
-                     D src/ref_name.rs
-                     D src/worktree.rs
-                    ?? src/config.rs
-                    ?? src/git/
-                    ?? src/utf8tempdir.rs
-                    !! target/
-                    "
-                )
-                .replace('\n', "\0")
-            )
-            .unwrap()
-            .entries,
+            Status::from_str(&COMPLEX_STATUS.replace('\n', "\0"))
+                .unwrap()
+                .entries,
             vec![
                 StatusEntry {
                     left: StatusCode::Unmodified,
@@ -404,6 +473,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_status_ignored() {
+        let status = Status::from_str(&COMPLEX_STATUS.replace('\n', "\0")).unwrap();
+        assert_eq!(paths(status.ignored()), vec!["target/"]);
+    }
+
+    #[test]
+    fn test_status_untracked() {
+        let status = Status::from_str(&COMPLEX_STATUS.replace('\n', "\0")).unwrap();
+        assert_eq!(
+            paths(status.untracked()),
+            vec!["src/config.rs", "src/git/", "src/utf8tempdir.rs"]
+        );
+    }
+
+    #[test]
+    fn test_status_modified() {
+        let status = Status::from_str(&COMPLEX_STATUS.replace('\n', "\0")).unwrap();
+        assert_eq!(
+            paths(status.modified()),
+            vec![
+                "Cargo.lock",
+                "Cargo.toml",
+                "src/app.rs",
+                "src/cli.rs",
+                "src/commit_hash.rs",
+                "src/git.rs",
+                "src/main.rs",
+                "src/ref_name.rs",
+                "src/worktree.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_status_staged() {
+        let status =
+            Status::from_str("M  staged.md  M unstaged.md MM both.md A  added.md ?? new.md ")
+                .unwrap();
+        assert_eq!(
+            paths(status.staged()),
+            vec!["staged.md", "both.md", "added.md"]
+        );
+    }
+
     #[test]
     fn test_status_parse_renamed() {
         assert_eq!(
@@ -418,4 +532,47 @@ mod tests {
             }]
         );
     }
+
+    #[test]
+    fn test_status_parse_copied() {
+        assert_eq!(
+            Status::from_str("C  PUPPY.md\0README.md\0 M src/main.rs\0")
+                .unwrap()
+                .entries,
+            vec![
+                StatusEntry {
+                    left: StatusCode::Copied,
+                    right: StatusCode::Unmodified,
+                    path: "PUPPY.md".into(),
+                    renamed_from: Some("README.md".into()),
+                },
+                StatusEntry {
+                    left: StatusCode::Unmodified,
+                    right: StatusCode::Modified,
+                    path: "src/main.rs".into(),
+                    renamed_from: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_status_entry_display() {
+        assert_eq!(
+            StatusEntry::from_str("R  PUPPY.md\0README.md\0")
+                .unwrap()
+                .to_string(),
+            "R  README.md -> PUPPY.md"
+        );
+        assert_eq!(
+            StatusEntry::from_str("CM PUPPY.md\0README.md\0")
+                .unwrap()
+                .to_string(),
+            "CM README.md -> PUPPY.md"
+        );
+        assert_eq!(
+            StatusEntry::from_str(" M README.md\0").unwrap().to_string(),
+            " M README.md"
+        );
+    }
 }
@@ -0,0 +1,408 @@
+use std::str::FromStr;
+
+use camino::Utf8PathBuf;
+use miette::miette;
+
+use super::CommitHash;
+use super::LocalBranchRef;
+use super::Status;
+use super::StatusCode;
+use super::StatusEntry;
+
+/// Branch information from `git status --porcelain=v2 --branch`.
+///
+/// ```plain
+/// # branch.oid 4023d0807b0e2ba2c4b6b4b5c2e8b7e1e4a0f2a1
+/// # branch.head puppy
+/// # branch.upstream origin/puppy
+/// # branch.ab +1 -2
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BranchStatus {
+    /// The current commit, or `None` if the branch is unborn.
+    pub oid: Option<CommitHash>,
+    /// The current branch, or `None` if `HEAD` is detached.
+    pub head: Option<LocalBranchRef>,
+    /// The upstream branch, like `origin/puppy`, if one is set.
+    pub upstream: Option<String>,
+    /// The number of commits ahead of the upstream branch, if the upstream branch exists.
+    pub ahead: Option<u64>,
+    /// The number of commits behind the upstream branch, if the upstream branch exists.
+    pub behind: Option<u64>,
+}
+
+/// The state of a submodule in a [`StatusV2Entry`], like `SC.U`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubmoduleStatus {
+    /// The submodule's commit changed.
+    pub commit_changed: bool,
+    /// The submodule has tracked changes.
+    pub modified: bool,
+    /// The submodule has untracked files.
+    pub untracked: bool,
+}
+
+impl FromStr for SubmoduleStatus {
+    type Err = miette::Report;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let flags = input
+            .strip_prefix('S')
+            .filter(|flags| flags.len() == 3)
+            .ok_or_else(|| miette!("Invalid submodule state: {input:?}"))?
+            .as_bytes();
+        Ok(Self {
+            commit_changed: flags[0] == b'C',
+            modified: flags[1] == b'M',
+            untracked: flags[2] == b'U',
+        })
+    }
+}
+
+/// The status of a particular file, with the extra information `--porcelain=v2` provides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusV2Entry {
+    pub entry: StatusEntry,
+    /// The state of the submodule at this path, if it's a submodule.
+    pub submodule: Option<SubmoduleStatus>,
+    /// For renamed and copied entries, the percentage similarity between the source and
+    /// destination.
+    pub score: Option<u8>,
+}
+
+impl StatusV2Entry {
+    fn new(entry: StatusEntry) -> Self {
+        Self {
+            entry,
+            submodule: None,
+            score: None,
+        }
+    }
+
+    /// Parse an ordinary (`1`), renamed or copied (`2`), or unmerged (`u`) entry.
+    ///
+    /// `fields` is the number of space-separated fields before the path, and `renamed_from` is
+    /// the following NUL-separated record for renamed and copied entries.
+    fn parse_changed(
+        record: &str,
+        fields: usize,
+        renamed_from: impl FnOnce() -> Option<String>,
+    ) -> miette::Result<Self> {
+        let invalid = || miette!("Invalid `git status --porcelain=v2` entry: {record:?}");
+        let parts = record.splitn(fields + 1, ' ').collect::<Vec<_>>();
+        if parts.len() != fields + 1 {
+            return Err(invalid());
+        }
+
+        let (left, right) = status_codes(parts[1]).ok_or_else(invalid)?;
+        let submodule = match parts[2] {
+            "N..." => None,
+            state => Some(state.parse()?),
+        };
+        let mut entry = Self {
+            entry: StatusEntry {
+                left,
+                right,
+                path: Utf8PathBuf::from(parts[fields]),
+                renamed_from: None,
+            },
+            submodule,
+            score: None,
+        };
+
+        if parts[0] == "2" {
+            // The score is like `R100` or `C75`.
+            let score = &parts[fields - 1];
+            entry.score = Some(
+                score
+                    .get(1..)
+                    .and_then(|score| score.parse().ok())
+                    .ok_or_else(invalid)?,
+            );
+            entry.entry.renamed_from = Some(Utf8PathBuf::from(renamed_from().ok_or_else(invalid)?));
+        }
+
+        Ok(entry)
+    }
+}
+
+/// Parse a `--porcelain=v2` `XY` field, where unmodified entries are `.` rather than ` `.
+fn status_codes(xy: &str) -> Option<(StatusCode, StatusCode)> {
+    let mut codes = xy.chars().map(|code| {
+        let code = if code == '.' { ' ' } else { code };
+        StatusCode::parser(&mut code.to_string().as_str()).ok()
+    });
+    let left = codes.next()??;
+    let right = codes.next()??;
+    if codes.next().is_some() {
+        return None;
+    }
+    Some((left, right))
+}
+
+/// A `git status --porcelain=v2 --branch` listing.
+///
+/// Unlike [`Status`], this includes branch and upstream information, submodule states, and
+/// rename scores.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusV2 {
+    pub branch: BranchStatus,
+    pub entries: Vec<StatusV2Entry>,
+}
+
+impl StatusV2 {
+    pub fn is_clean(&self) -> bool {
+        self.entries.iter().all(|entry| !entry.entry.is_modified())
+    }
+
+    fn parse_header(&mut self, header: &str) -> miette::Result<()> {
+        let (key, value) = header
+            .split_once(' ')
+            .ok_or_else(|| miette!("Invalid `git status --porcelain=v2` header: {header:?}"))?;
+        match key {
+            "branch.oid" => {
+                if value != "(initial)" {
+                    self.branch.oid = Some(CommitHash::new(value.to_owned()));
+                }
+            }
+            "branch.head" => {
+                if value != "(detached)" {
+                    self.branch.head = Some(LocalBranchRef::new(value.to_owned()));
+                }
+            }
+            "branch.upstream" => {
+                self.branch.upstream = Some(value.to_owned());
+            }
+            "branch.ab" => {
+                let counts = value
+                    .split_once(' ')
+                    .and_then(|(ahead, behind)| {
+                        Some((
+                            ahead.strip_prefix('+')?.parse().ok()?,
+                            behind.strip_prefix('-')?.parse().ok()?,
+                        ))
+                    })
+                    .ok_or_else(|| miette!("Invalid ahead/behind counts: {value:?}"))?;
+                self.branch.ahead = Some(counts.0);
+                self.branch.behind = Some(counts.1);
+            }
+            _ => {
+                // Other headers, like `stash`, aren't used.
+                tracing::trace!(header, "Ignoring `git status` header");
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<StatusV2> for Status {
+    fn from(status: StatusV2) -> Self {
+        Self {
+            entries: status
+                .entries
+                .into_iter()
+                .map(|entry| entry.entry)
+                .collect(),
+        }
+    }
+}
+
+/// Parses the output of `git status --porcelain=v2 --branch -z`.
+impl FromStr for StatusV2 {
+    type Err = miette::Report;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut status = Self::default();
+        let mut records = input.split_terminator('\0');
+
+        while let Some(record) = records.next() {
+            let (kind, rest) = record.split_once(' ').unwrap_or((record, ""));
+            match kind {
+                "#" => status.parse_header(rest)?,
+                "1" => status
+                    .entries
+                    .push(StatusV2Entry::parse_changed(record, 8, || None)?),
+                "2" => status
+                    .entries
+                    .push(StatusV2Entry::parse_changed(record, 9, || {
+                        records.next().map(ToOwned::to_owned)
+                    })?),
+                "u" => status
+                    .entries
+                    .push(StatusV2Entry::parse_changed(record, 10, || None)?),
+                "?" | "!" => {
+                    let code = if kind == "?" {
+                        StatusCode::Untracked
+                    } else {
+                        StatusCode::Ignored
+                    };
+                    status.entries.push(StatusV2Entry::new(StatusEntry {
+                        left: code,
+                        right: code,
+                        path: Utf8PathBuf::from(rest),
+                        renamed_from: None,
+                    }));
+                }
+                _ => {
+                    return Err(miette!(
+                        "Invalid `git status --porcelain=v2` entry: {record:?}"
+                    ));
+                }
+            }
+        }
+
+        Ok(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_status_v2_parse_empty() {
+        assert_eq!(StatusV2::from_str("").unwrap(), StatusV2::default());
+    }
+
+    #[test]
+    fn test_status_v2_parse_branch() {
+        assert_eq!(
+            StatusV2::from_str(
+                &indoc!(
+                    "
+                    # branch.oid 4023d0807b0e2ba2c4b6b4b5c2e8b7e1e4a0f2a1
+                    # branch.head puppy
+                    # branch.upstream origin/puppy
+                    # branch.ab +1 -2
+                    "
+                )
+                .replace('\n', "\0")
+            )
+            .unwrap()
+            .branch,
+            BranchStatus {
+                oid: Some(CommitHash::new(
+                    "4023d0807b0e2ba2c4b6b4b5c2e8b7e1e4a0f2a1".to_owned()
+                )),
+                head: Some(LocalBranchRef::new("puppy".to_owned())),
+                upstream: Some("origin/puppy".to_owned()),
+                ahead: Some(1),
+                behind: Some(2),
+            }
+        );
+    }
+
+    #[test]
+    fn test_status_v2_parse_unborn_detached() {
+        assert_eq!(
+            StatusV2::from_str("# branch.oid (initial)\0# branch.head (detached)\0")
+                .unwrap()
+                .branch,
+            BranchStatus::default()
+        );
+    }
+
+    #[test]
+    fn test_status_v2_parse_entries() {
+        let hash = "a".repeat(40);
+        let input = [
+            format!("1 .M N... 100644 100644 100644 {hash} {hash} src/main.rs"),
+            format!("1 M. SC.U 160000 160000 160000 {hash} {hash} vendor/puppy lib"),
+            format!("2 R. N... 100644 100644 100644 {hash} {hash} R87 PUPPY.md"),
+            "README.md".to_owned(),
+            format!("u UU N... 100644 100644 100644 100644 {hash} {hash} {hash} Cargo.lock"),
+            "? src/config.rs".to_owned(),
+            "! target/".to_owned(),
+        ]
+        .into_iter()
+        .map(|record| record + "\0")
+        .collect::<String>();
+
+        let status = StatusV2::from_str(&input).unwrap();
+        assert_eq!(
+            status.entries,
+            vec![
+                StatusV2Entry {
+                    entry: StatusEntry {
+                        left: StatusCode::Unmodified,
+                        right: StatusCode::Modified,
+                        path: "src/main.rs".into(),
+                        renamed_from: None,
+                    },
+                    submodule: None,
+                    score: None,
+                },
+                StatusV2Entry {
+                    entry: StatusEntry {
+                        left: StatusCode::Modified,
+                        right: StatusCode::Unmodified,
+                        path: "vendor/puppy lib".into(),
+                        renamed_from: None,
+                    },
+                    submodule: Some(SubmoduleStatus {
+                        commit_changed: true,
+                        modified: false,
+                        untracked: true,
+                    }),
+                    score: None,
+                },
+                StatusV2Entry {
+                    entry: StatusEntry {
+                        left: StatusCode::Renamed,
+                        right: StatusCode::Unmodified,
+                        path: "PUPPY.md".into(),
+                        renamed_from: Some("README.md".into()),
+                    },
+                    submodule: None,
+                    score: Some(87),
+                },
+                StatusV2Entry {
+                    entry: StatusEntry {
+                        left: StatusCode::Unmerged,
+                        right: StatusCode::Unmerged,
+                        path: "Cargo.lock".into(),
+                        renamed_from: None,
+                    },
+                    submodule: None,
+                    score: None,
+                },
+                StatusV2Entry::new(StatusEntry {
+                    left: StatusCode::Untracked,
+                    right: StatusCode::Untracked,
+                    path: "src/config.rs".into(),
+                    renamed_from: None,
+                }),
+                StatusV2Entry::new(StatusEntry {
+                    left: StatusCode::Ignored,
+                    right: StatusCode::Ignored,
+                    path: "target/".into(),
+                    renamed_from: None,
+                }),
+            ]
+        );
+        assert!(!status.is_clean());
+        assert_eq!(
+            Status::from(status)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                " M src/main.rs",
+                "M  vendor/puppy lib",
+                "R  README.md -> PUPPY.md",
+                "UU Cargo.lock",
+                "?? src/config.rs",
+                "!! target/",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_status_v2_parse_invalid() {
+        assert!(StatusV2::from_str("1 .M N...\0").is_err());
+        assert!(StatusV2::from_str("# branch.ab 1 2\0").is_err());
+    }
+}
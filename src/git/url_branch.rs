@@ -0,0 +1,95 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use miette::miette;
+
+/// A branch on a remote repository given by URL, written like `URL:BRANCH`.
+///
+/// Branch names cannot contain colons, so the URL is everything before the last colon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlBranch {
+    pub url: String,
+    pub branch: String,
+}
+
+impl FromStr for UrlBranch {
+    type Err = miette::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (url, branch) = s
+            .rsplit_once(':')
+            .ok_or_else(|| miette!("Expected `URL:BRANCH`, but found no `:` in {s}"))?;
+
+        if url.is_empty() {
+            return Err(miette!(
+                "Expected `URL:BRANCH`, but the URL is empty in {s}"
+            ));
+        }
+
+        // This catches URLs with no branch like `https://github.com/puppy/doggy`, where we'd
+        // split after `https`.
+        if branch.is_empty() || branch.starts_with('/') || branch.contains("//") {
+            return Err(miette!(
+                "Expected `URL:BRANCH`, but found no branch name in {s}"
+            ));
+        }
+
+        Ok(Self {
+            url: url.to_owned(),
+            branch: branch.to_owned(),
+        })
+    }
+}
+
+impl Display for UrlBranch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.url, self.branch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[track_caller]
+    fn url_branch(url: &str, branch: &str) -> UrlBranch {
+        UrlBranch {
+            url: url.to_owned(),
+            branch: branch.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_url_branch_parse() {
+        assert_eq!(
+            "git@github.com:puppy/doggy.git:silly/branch"
+                .parse::<UrlBranch>()
+                .unwrap(),
+            url_branch("git@github.com:puppy/doggy.git", "silly/branch")
+        );
+        assert_eq!(
+            "https://github.com/puppy/doggy.git:main"
+                .parse::<UrlBranch>()
+                .unwrap(),
+            url_branch("https://github.com/puppy/doggy.git", "main")
+        );
+        assert_eq!(
+            "../doggy:puppy".parse::<UrlBranch>().unwrap(),
+            url_branch("../doggy", "puppy")
+        );
+    }
+
+    #[test]
+    fn test_url_branch_parse_invalid() {
+        assert!("puppy".parse::<UrlBranch>().is_err());
+        assert!(":puppy".parse::<UrlBranch>().is_err());
+        assert!("git@github.com:puppy/doggy.git:"
+            .parse::<UrlBranch>()
+            .is_err());
+        assert!("https://github.com/puppy/doggy.git"
+            .parse::<UrlBranch>()
+            .is_err());
+    }
+}
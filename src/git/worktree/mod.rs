@@ -14,7 +14,6 @@ use tap::Tap;
 use tracing::instrument;
 use utf8_command::Utf8Output;
 
-use crate::config::BranchReplacement;
 use crate::final_component;
 use crate::AppGit;
 
@@ -65,19 +64,22 @@ where
         Ok(self.list()?.into_main())
     }
 
-    /// Get the worktree container directory.
+    /// Get the worktree container directory, `depth` levels above the main worktree.
     ///
-    /// This is the main worktree's parent, and is usually where all the other worktrees are
-    /// cloned as well.
+    /// With a `depth` of 1, this is the main worktree's parent.
     #[instrument(level = "trace")]
-    pub fn container(&self) -> miette::Result<Utf8PathBuf> {
+    pub fn container_at_depth(&self, depth: usize) -> miette::Result<Utf8PathBuf> {
         // TODO: Write `.git-prole` to indicate worktree container root?
-        let mut path = self.main()?.path;
-        if !path.pop() {
-            Err(miette!("Main worktree path has no parent: {path}"))
-        } else {
-            Ok(path)
+        let main = self.main()?.path;
+        let mut path = main.clone();
+        for _ in 0..depth {
+            if !path.pop() {
+                return Err(miette!(
+                    "Main worktree path has fewer than {depth} parents: {main}"
+                ));
+            }
         }
+        Ok(path)
     }
 
     /// List Git worktrees.
@@ -174,6 +176,19 @@ where
             command.arg("--track");
         }
 
+        if options.no_track {
+            command.arg("--no-track");
+        }
+
+        if let Some(reason) = options.lock {
+            command.arg("--lock");
+            if !reason.is_empty() {
+                command.args(["--reason", reason]);
+            }
+        }
+
+        command.args(options.extra_args);
+
         command.arg(path.as_str());
 
         if let Some(start_point) = options.start_point {
@@ -183,6 +198,29 @@ where
         command
     }
 
+    /// `git worktree remove`, with `--force` if `force` is set.
+    #[instrument(level = "trace")]
+    pub fn remove_command(&self, path: &Utf8Path, force: bool) -> Command {
+        let mut command = self.0.command();
+        command.args(["worktree", "remove"]);
+        if force {
+            command.arg("--force");
+        }
+        command.arg(path.as_str());
+        command
+    }
+
+    /// `git worktree prune`, with `--expire` if `expire` is set.
+    #[instrument(level = "trace")]
+    pub fn prune_command(&self, expire: Option<&str>) -> Command {
+        let mut command = self.0.command();
+        command.args(["worktree", "prune"]);
+        if let Some(expire) = expire {
+            command.args(["--expire", expire]);
+        }
+        command
+    }
+
     #[instrument(level = "trace")]
     pub fn rename(&self, from: &Utf8Path, to: &Utf8Path) -> miette::Result<()> {
         self.0
@@ -193,6 +231,36 @@ where
         Ok(())
     }
 
+    /// Move a worktree and rename the branch checked out in it, keeping the two in sync.
+    ///
+    /// The branch is renamed first, so that the worktree's `HEAD` follows it, and then the
+    /// worktree is moved and repaired. If the move fails, the branch rename is rolled back.
+    #[instrument(level = "trace")]
+    pub fn move_and_rename_branch(
+        &self,
+        from: &Utf8Path,
+        to: &Utf8Path,
+        branch: &LocalBranchRef,
+        new_branch: &LocalBranchRef,
+    ) -> miette::Result<()> {
+        // Test: `worktree_move_and_rename_branch`
+        self.0.branch().rename(branch, new_branch)?;
+
+        if let Err(error) = self.rename(from, to) {
+            // Test: `worktree_move_and_rename_branch_rollback`
+            tracing::debug!(%branch, %new_branch, "Moving worktree failed, rolling back branch rename");
+            if let Err(rollback_error) = self.0.branch().rename(new_branch, branch) {
+                return Err(error.wrap_err(format!(
+                    "Failed to roll back renaming branch {branch} to {new_branch}: {rollback_error}"
+                )));
+            }
+            return Err(error);
+        }
+
+        self.repair([to])?;
+        Ok(())
+    }
+
     #[instrument(level = "trace")]
     pub fn repair(
         &self,
@@ -224,11 +292,18 @@ pub struct AddWorktreeOpts<'a> {
     /// If true, use `--track`.
     /// Default false.
     pub track: bool,
+    /// If true, use `--no-track`.
+    /// Default false.
+    pub no_track: bool,
     /// The start point for the new worktree.
     pub start_point: Option<&'a str>,
     /// If true, use `--detach`.
     /// Default false.
     pub detach: bool,
+    /// If set, use `--lock`, with the given `--reason` if it's not empty.
+    pub lock: Option<&'a str>,
+    /// Extra arguments to pass before the path.
+    pub extra_args: &'a [String],
 }
 
 impl<'a> Default for AddWorktreeOpts<'a> {
@@ -239,8 +314,11 @@ impl<'a> Default for AddWorktreeOpts<'a> {
             checkout: true,
             guess_remote: false,
             track: false,
+            no_track: false,
             start_point: None,
             detach: false,
+            lock: None,
+            extra_args: &[],
         }
     }
 }
@@ -249,6 +327,32 @@ impl<'a, C> GitWorktree<'a, AppGit<'a, C>>
 where
     C: AsRef<Utf8Path>,
 {
+    /// Get the worktree container directory.
+    ///
+    /// This is the main worktree's parent (or an ancestor, according to the
+    /// `worktree_parent_depth` and `repo_root_markers` settings), and is usually where all the
+    /// other worktrees are cloned as well.
+    #[instrument(level = "trace")]
+    pub fn container(&self) -> miette::Result<Utf8PathBuf> {
+        let markers = self.0.config.file.repo_root_markers();
+        if !markers.is_empty() {
+            let main = self.main()?.path;
+            for ancestor in main.ancestors().skip(1) {
+                if let Some(marker) = markers.iter().find(|marker| ancestor.join(marker).exists()) {
+                    // Test: `config_repo_root_markers`
+                    tracing::debug!(%ancestor, %marker, "Found repository root marker");
+                    return Ok(ancestor.to_owned());
+                }
+            }
+            tracing::debug!(%main, "No repository root markers found above main worktree");
+        }
+
+        // Tests:
+        // - `config_worktree_parent_depth`
+        // - `config_worktree_parent_depth_default`
+        self.container_at_depth(self.0.config.file.worktree_parent_depth())
+    }
+
     /// The directory name, nested under the worktree parent directory, where the given
     /// branch's worktree will be placed.
     ///
@@ -259,19 +363,11 @@ where
         if branch_replacements.is_empty() {
             Cow::Borrowed(final_component(branch))
         } else {
-            let mut dirname = branch.to_owned();
-            for BranchReplacement {
-                find,
-                replace,
-                count,
-            } in branch_replacements
-            {
-                dirname = match count {
-                    Some(count) => find.replacen(&dirname, *count, replace),
-                    None => find.replace_all(&dirname, replace),
-                }
-                .into_owned();
-            }
+            let dirname = branch_replacements
+                .iter()
+                .fold(branch.to_owned(), |dirname, replacement| {
+                    replacement.apply(&dirname)
+                });
 
             if dirname.contains(std::path::MAIN_SEPARATOR_STR) {
                 let final_component = final_component(&dirname);
@@ -299,6 +395,19 @@ where
             .tap_mut(|p| p.push(&*self.dirname_for(branch))))
     }
 
+    /// Get the first unused path for a new scratch worktree, named after the
+    /// `add.scratch_prefix` setting.
+    #[instrument(level = "trace")]
+    pub fn scratch_path(&self) -> miette::Result<Utf8PathBuf> {
+        let container = self.container()?;
+        Ok(
+            resolve_unique_names::scratch_names(self.0.config.file.add.scratch_prefix())
+                .map(|name| container.join(&*name))
+                .find(|path| !path.exists())
+                .expect("There are an infinite number of possible scratch worktree names"),
+        )
+    }
+
     /// Resolves a set of worktrees into a map from worktree paths to unique names.
     #[instrument(level = "trace")]
     pub fn resolve_unique_names(
@@ -321,7 +430,7 @@ where
         };
         let preferred_branch = match preferred_branch {
             Some(preferred_branch) => preferred_branch,
-            None => &match self.0.branch().preferred()? {
+            None => &match self.0.main_branch()? {
                 Some(preferred_branch) => preferred_branch,
                 None => {
                     return Ok(None);
@@ -357,18 +466,22 @@ where
 
         tracing::debug!("No worktree for preferred branch");
 
-        if worktrees.main().head.is_bare() && worktrees.len() > 1 {
+        if worktrees.main().head.is_bare() {
             // Find a non-bare worktree.
             //
-            // Test: `add_from_container_no_default_branch`
-            let worktree = worktrees
-                .into_iter()
+            // We only consider the worktrees Git knows about, so unrelated bare repositories
+            // nested inside a worktree are ignored.
+            //
+            // Tests:
+            // - `add_from_container_no_default_branch`
+            // - `add_nested_bare_repo`
+            if let Some((worktree, _)) = worktrees
+                .iter()
                 .find(|(_path, worktree)| !worktree.head.is_bare())
-                .expect("Only one worktree can be bare")
-                .0;
-
-            tracing::debug!(%worktree, "Found non-bare worktree");
-            return Ok(worktree);
+            {
+                tracing::debug!(%worktree, "Found non-bare worktree");
+                return Ok(worktree.clone());
+            }
         }
 
         // Otherwise, get the main worktree.
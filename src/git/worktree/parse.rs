@@ -3,7 +3,9 @@ use std::ops::Deref;
 
 use camino::Utf8Path;
 use camino::Utf8PathBuf;
+use itertools::Itertools;
 use miette::miette;
+use miette::IntoDiagnostic;
 use owo_colors::OwoColorize;
 use owo_colors::Stream;
 use rustc_hash::FxHashMap;
@@ -20,6 +22,7 @@ use winnow::stream::Stream as _;
 use winnow::PResult;
 use winnow::Parser;
 
+use crate::format_bulleted_list;
 use crate::git::GitLike;
 use crate::parse::till_null;
 use crate::CommitHash;
@@ -27,6 +30,7 @@ use crate::LocalBranchRef;
 use crate::PathDisplay;
 use crate::Ref;
 use crate::ResolvedCommitish;
+use crate::Utf8Absolutize;
 
 /// A set of Git worktrees.
 ///
@@ -63,6 +67,74 @@ impl Worktrees {
             .find(|worktree| worktree.head.branch() == Some(branch))
     }
 
+    /// Find the worktree containing the given path.
+    ///
+    /// If worktrees are nested in each other, the innermost worktree is returned.
+    pub fn containing(&self, path: &Utf8Path) -> Option<&Worktree> {
+        self.values()
+            .filter(|worktree| path.starts_with(&worktree.path))
+            .max_by_key(|worktree| worktree.path.components().count())
+    }
+
+    /// Find a worktree by its branch, path, name, or an unambiguous prefix of one of those.
+    ///
+    /// In order, this tries:
+    /// - A worktree with `name` checked out as its branch.
+    /// - If `name` contains a `/`, a worktree at that path, relative to the current directory.
+    /// - A worktree with `name` as its directory name.
+    /// - A single worktree whose branch or directory name starts with `name`, or whose detached
+    ///   `HEAD` commit hash starts with `name`.
+    pub fn resolve(&self, name: &str) -> miette::Result<&Worktree> {
+        if let Some(worktree) = self.for_branch(&LocalBranchRef::new(name.to_owned())) {
+            return Ok(worktree);
+        }
+
+        if name.contains('/') {
+            let path = Utf8Path::new(name).absolutize().into_diagnostic()?;
+            if let Some(worktree) = self.get(path.as_ref()) {
+                return Ok(worktree);
+            }
+        }
+
+        let by_name = self
+            .values()
+            .filter(|worktree| worktree.path.file_name() == Some(name))
+            .collect::<Vec<_>>();
+        match by_name.as_slice() {
+            [worktree] => return Ok(worktree),
+            [] => {}
+            _ => {
+                return Err(miette!(
+                    "Multiple worktrees are named {name}; use a path instead"
+                ));
+            }
+        }
+
+        let candidates = self
+            .values()
+            .filter(|worktree| {
+                worktree
+                    .path
+                    .file_name()
+                    .is_some_and(|dirname| dirname.starts_with(name))
+                    || match &worktree.head {
+                        WorktreeHead::Bare => false,
+                        WorktreeHead::Detached(commit) => format!("{commit:#}").starts_with(name),
+                        WorktreeHead::Branch(_, branch) => branch.branch_name().starts_with(name),
+                    }
+            })
+            .sorted_by_key(|worktree| &worktree.path)
+            .collect::<Vec<_>>();
+        match candidates.as_slice() {
+            [worktree] => Ok(worktree),
+            [] => Err(miette!("No worktree found named {name}")),
+            _ => Err(miette!(
+                "Multiple worktrees match {name}:\n{}",
+                format_bulleted_list(candidates)
+            )),
+        }
+    }
+
     fn parser(input: &mut &str) -> PResult<Self> {
         let mut main = Worktree::parser.parse_next(input)?;
         main.is_main = true;
@@ -193,6 +265,11 @@ impl WorktreeHead {
         matches!(&self, WorktreeHead::Detached(_))
     }
 
+    /// Is this worktree on an unborn branch, i.e. one with no commits yet?
+    pub fn is_unborn(&self) -> bool {
+        self.commit().is_some_and(CommitHash::is_null)
+    }
+
     pub fn parser(input: &mut &str) -> PResult<Self> {
         alt(("bare\0".map(|_| Self::Bare), Self::parse_non_bare)).parse_next(input)
     }
@@ -297,6 +374,31 @@ impl Display for Worktree {
 }
 
 impl Worktree {
+    /// Format this worktree like `git worktree list --porcelain -z`, without the terminating
+    /// null byte.
+    pub fn porcelain(&self) -> String {
+        let mut output = format!("worktree {}\0", self.path);
+        match &self.head {
+            WorktreeHead::Bare => {
+                output.push_str("bare\0");
+            }
+            WorktreeHead::Detached(commit) => {
+                output.push_str(&format!("HEAD {commit:#}\0detached\0"));
+            }
+            WorktreeHead::Branch(commit, branch) => {
+                output.push_str(&format!("HEAD {commit:#}\0branch {:#}\0", branch.deref()));
+            }
+        }
+        for (key, reason) in [("locked", &self.locked), ("prunable", &self.prunable)] {
+            match reason.as_deref() {
+                None => {}
+                Some("") => output.push_str(&format!("{key}\0")),
+                Some(reason) => output.push_str(&format!("{key} {reason}\0")),
+            }
+        }
+        output
+    }
+
     fn parser(input: &mut &str) -> PResult<Self> {
         let _ = "worktree ".parse_next(input)?;
         let path = Utf8PathBuf::from(till_null.parse_next(input)?);
@@ -486,4 +588,150 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_worktree_porcelain() {
+        for worktree in [
+            Worktree::new_bare("/path/to/bare-source"),
+            Worktree::new_branch(
+                "/Users/wiggles/cabal/accept",
+                "0685cb3fec8b7144f865638cfd16768e15125fc2",
+                "rebeccat/fix-accept-flag",
+            ),
+            Worktree::new_detached(
+                "/Users/wiggles/lix",
+                "0d484aa498b3c839991d11afb31bc5fcf368493d",
+            )
+            .with_locked(""),
+            Worktree::new_branch(
+                "/path/to/linked-worktree-prunable",
+                "1233def1234def1234def1234def1234def1234b",
+                "prunable",
+            )
+            .with_locked("reason why is locked")
+            .with_prunable("gitdir file points to non-existent location"),
+        ] {
+            let mut parsed = Worktree::parser
+                .parse(&format!("{}\0", worktree.porcelain()))
+                .unwrap();
+            parsed.is_main = worktree.is_main;
+            assert_eq!(parsed, worktree);
+        }
+    }
+
+    #[test]
+    fn test_worktrees_containing() {
+        let worktrees = Worktrees {
+            main: "/puppy".into(),
+            inner: [
+                Worktree::new_branch("/puppy", CommitHash::fake(), "main").with_is_main(true),
+                Worktree::new_branch("/puppy/doggy", CommitHash::fake(), "doggy"),
+                Worktree::new_branch("/puppy-2", CommitHash::fake(), "puppy-2"),
+            ]
+            .into_iter()
+            .map(|worktree| (worktree.path.clone(), worktree))
+            .collect(),
+        };
+
+        let containing = |path: &str| {
+            worktrees
+                .containing(Utf8Path::new(path))
+                .map(|worktree| worktree.path.as_str())
+        };
+
+        assert_eq!(containing("/puppy"), Some("/puppy"));
+        assert_eq!(containing("/puppy/src/main.rs"), Some("/puppy"));
+        assert_eq!(containing("/puppy/doggy"), Some("/puppy/doggy"));
+        assert_eq!(containing("/puppy/doggy/src/main.rs"), Some("/puppy/doggy"));
+        // Paths are compared by component, not as strings.
+        assert_eq!(containing("/puppy-2/README.md"), Some("/puppy-2"));
+        assert_eq!(containing("/kitty"), None);
+    }
+
+    #[test]
+    fn test_worktrees_resolve_paths() {
+        let worktrees = Worktrees {
+            main: "/puppy/.git".into(),
+            inner: [
+                Worktree::new_bare("/puppy/.git").with_is_main(true),
+                Worktree::new_branch("/puppy/main", CommitHash::fake(), "main"),
+                Worktree::new_branch("/puppy/doggy", CommitHash::fake(), "silly/doggy"),
+                Worktree::new_detached("/puppy/work", CommitHash::fake()),
+                Worktree::new_detached("/kitty/work", CommitHash::fake()),
+            ]
+            .into_iter()
+            .map(|worktree| (worktree.path.clone(), worktree))
+            .collect(),
+        };
+
+        assert_eq!(worktrees.resolve("main").unwrap().path, "/puppy/main");
+        assert_eq!(
+            worktrees.resolve("silly/doggy").unwrap().path,
+            "/puppy/doggy"
+        );
+        assert_eq!(
+            worktrees.resolve("/puppy/doggy").unwrap().path,
+            "/puppy/doggy"
+        );
+        assert_eq!(
+            worktrees.resolve("/kitty/work").unwrap().path,
+            "/kitty/work"
+        );
+        assert_eq!(
+            worktrees.resolve("work").unwrap_err().to_string(),
+            "Multiple worktrees are named work; use a path instead"
+        );
+        assert_eq!(
+            worktrees.resolve("kitty").unwrap_err().to_string(),
+            "No worktree found named kitty"
+        );
+    }
+
+    #[test]
+    fn test_worktrees_resolve() {
+        let worktrees = Worktrees {
+            main: "/puppy/.git".into(),
+            inner: [
+                Worktree::new_bare("/puppy/.git").with_is_main(true),
+                Worktree::new_branch("/puppy/main", CommitHash::fake(), "main"),
+                Worktree::new_branch("/puppy/login", CommitHash::fake(), "feature/login"),
+                Worktree::new_branch("/puppy/feat", CommitHash::fake(), "feat"),
+                Worktree::new_branch("/puppy/logout", CommitHash::fake(), "feature/logout"),
+                Worktree::new_detached("/puppy/work", "4023d0806f6b1b8ce4d8da5e5ea5df4b8b6e5e62"),
+            ]
+            .into_iter()
+            .map(|worktree| (worktree.path.clone(), worktree))
+            .collect(),
+        };
+
+        let resolve = |name| {
+            worktrees
+                .resolve(name)
+                .map(|worktree| worktree.path.as_str())
+        };
+
+        // Exact branches.
+        assert_eq!(resolve("feature/login").unwrap(), "/puppy/login");
+        // Exact branches are preferred over prefixes.
+        assert_eq!(resolve("feat").unwrap(), "/puppy/feat");
+        // Exact directory names.
+        assert_eq!(resolve("work").unwrap(), "/puppy/work");
+        // Unique prefixes of branches and directory names.
+        assert_eq!(resolve("feature/logi").unwrap(), "/puppy/login");
+        assert_eq!(resolve("logo").unwrap(), "/puppy/logout");
+        assert_eq!(resolve("ma").unwrap(), "/puppy/main");
+        // Detached worktrees by commit hash.
+        assert_eq!(resolve("4023d08").unwrap(), "/puppy/work");
+
+        assert_eq!(
+            resolve("feature/").unwrap_err().to_string(),
+            "Multiple worktrees match feature/:\n\
+            • /puppy/login feature/login\n\
+            • /puppy/logout feature/logout"
+        );
+        assert_eq!(
+            resolve("kitty").unwrap_err().to_string(),
+            "No worktree found named kitty"
+        );
+    }
 }
@@ -42,7 +42,9 @@ pub struct ResolveUniqueNameOpts<'a> {
 /// - The worktree's directory name.
 /// - The worktree's directory name with numbers appended (e.g. for `puppy`, this tries `puppy-2`,
 ///   `puppy-3`, etc.)
-/// - For a worktree with a detached `HEAD`, we try `work`, `work-2`, `work-3`, etc.
+/// - For a worktree with a detached `HEAD`, or one whose directory name is excluded, we try
+///   `work`, `work-2`, `work-3`, etc. (The `work` prefix is set by the `add.scratch_prefix`
+///   setting.)
 ///
 /// Anyways, this function resolves a bunch of worktrees into unique names.
 #[instrument(level = "trace")]
@@ -114,6 +116,12 @@ fn handle_bare_main_worktree(
     (resolved, worktrees)
 }
 
+/// Names for scratch worktrees: `{prefix}`, `{prefix}-2`, `{prefix}-3`, etc.
+pub(super) fn scratch_names(prefix: &str) -> impl Iterator<Item = Cow<'_, str>> {
+    std::iter::once(prefix.into())
+        .chain((2..).map(move |number| format!("{prefix}-{number}").into()))
+}
+
 /// A worktree with a new name.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RenamedWorktree {
@@ -182,11 +190,8 @@ where
     }
 
     fn detached_work_numbers(&self) -> Option<impl Iterator<Item = Cow<'a, str>>> {
-        if self.worktree.head.is_detached() {
-            Some(
-                std::iter::once("work".into())
-                    .chain((2..).map(|number| format!("work-{number}").into())),
-            )
+        if self.worktree.head.is_detached() || self.maybe_directory_name().is_none() {
+            Some(scratch_names(self.git.config.file.add.scratch_prefix()))
         } else {
             None
         }
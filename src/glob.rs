@@ -0,0 +1,142 @@
+use std::fmt::Display;
+
+use regex::Regex;
+use serde::de::Error;
+use serde::Deserialize;
+
+use crate::final_component;
+
+/// A glob pattern for matching paths, like `.env` or `*.local.toml`.
+///
+/// - `*` matches anything except a `/`.
+/// - `**` matches anything, including `/`.
+/// - `?` matches any single character except a `/`.
+///
+/// Like in `.gitignore` files, a pattern without a `/` is matched against the last component of
+/// a path, so `.env` matches both `.env` and `config/.env`. A trailing `/` on a path is ignored,
+/// so `target` matches `target/`.
+#[derive(Clone, Debug)]
+pub struct Glob {
+    pattern: String,
+    regex: Regex,
+}
+
+impl Glob {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        let mut regex = String::from("^");
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '*' => {
+                    if chars.peek() == Some(&'*') {
+                        chars.next();
+                        if chars.peek() == Some(&'/') {
+                            // `**/` matches zero or more directories.
+                            chars.next();
+                            regex.push_str("(?:.*/)?");
+                        } else {
+                            regex.push_str(".*");
+                        }
+                    } else {
+                        regex.push_str("[^/]*");
+                    }
+                }
+                '?' => regex.push_str("[^/]"),
+                c => regex.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+        regex.push('$');
+
+        Ok(Self {
+            pattern: pattern.to_owned(),
+            regex: Regex::new(&regex)?,
+        })
+    }
+
+    pub fn is_match(&self, path: &str) -> bool {
+        let path = path.strip_suffix('/').unwrap_or(path);
+        if self.pattern.contains('/') {
+            self.regex.is_match(path)
+        } else {
+            self.regex.is_match(final_component(path))
+        }
+    }
+}
+
+impl Display for Glob {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+impl PartialEq for Glob {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Eq for Glob {}
+
+impl<'de> Deserialize<'de> for Glob {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let pattern: String = Deserialize::deserialize(deserializer)?;
+        Self::new(&pattern).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn assert_matches(pattern: &str, path: &str) {
+        assert!(
+            Glob::new(pattern).unwrap().is_match(path),
+            "{pattern:?} should match {path:?}"
+        );
+    }
+
+    #[track_caller]
+    fn assert_not_matches(pattern: &str, path: &str) {
+        assert!(
+            !Glob::new(pattern).unwrap().is_match(path),
+            "{pattern:?} should not match {path:?}"
+        );
+    }
+
+    #[test]
+    fn test_glob_literal() {
+        assert_matches(".env", ".env");
+        assert_matches(".env", "config/.env");
+        assert_matches("target", "target/");
+        assert_not_matches(".env", ".envrc");
+        assert_not_matches(".env", "xenv");
+    }
+
+    #[test]
+    fn test_glob_star() {
+        assert_matches("*.local.toml", "puppy.local.toml");
+        assert_matches("*.local.toml", "config/puppy.local.toml");
+        assert_not_matches("*.local.toml", "puppy.toml");
+        assert_matches("config/*", "config/puppy");
+        assert_not_matches("config/*", "config/puppy/doggy");
+        assert_not_matches("config/*", "other/config/puppy");
+    }
+
+    #[test]
+    fn test_glob_double_star() {
+        assert_matches("**/.env", ".env");
+        assert_matches("**/.env", "a/b/.env");
+        assert_matches("config/**", "config/puppy/doggy");
+        assert_not_matches("config/**", "other/puppy");
+    }
+
+    #[test]
+    fn test_glob_question_mark() {
+        assert_matches("?.txt", "a.txt");
+        assert_not_matches("?.txt", "ab.txt");
+    }
+}
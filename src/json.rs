@@ -0,0 +1,29 @@
+use miette::IntoDiagnostic;
+use serde::Serialize;
+
+/// The version of the format of `git-prole`'s JSON output, included in every JSON payload as
+/// `schema_version`.
+///
+/// This is bumped whenever JSON output changes incompatibly, like when a field is removed,
+/// renamed, or changes type. Adding a field is not an incompatible change.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// A JSON payload with the [`JSON_SCHEMA_VERSION`] added.
+#[derive(Debug, Serialize)]
+struct Versioned<'a, T> {
+    schema_version: u32,
+    #[serde(flatten)]
+    payload: &'a T,
+}
+
+/// Serialize a payload as pretty-printed JSON, with a `schema_version` field.
+pub fn to_string_pretty<T>(payload: &T) -> miette::Result<String>
+where
+    T: Serialize,
+{
+    serde_json::to_string_pretty(&Versioned {
+        schema_version: JSON_SCHEMA_VERSION,
+        payload,
+    })
+    .into_diagnostic()
+}
@@ -8,34 +8,51 @@
 mod add;
 mod app;
 mod app_git;
+mod cd;
 mod cli;
 mod clone;
+mod code_workspace;
 mod config;
 mod convert;
 mod copy_dir;
 mod current_dir;
+mod default_branch_cache;
+mod exit_code;
 mod final_component;
 mod format_bulleted_list;
 pub mod fs;
 mod gh;
 mod git;
+mod glob;
 mod install_tracing;
+mod json;
+mod list;
 mod only_paths_in_parent_directory;
 mod parse;
 mod path_display;
+mod populate;
+mod prune;
+mod remove;
+mod safe_roots;
 mod topological_sort;
+mod utc_date;
 mod utf8absolutize;
 mod utf8tempdir;
+mod verify;
+mod whereami;
 
 pub use app::App;
 pub use app_git::AppGit;
+pub use cli::Verbosity;
 pub use config::Config;
+pub use exit_code::ErrorCode;
 pub use final_component::final_component;
 pub use format_bulleted_list::format_bulleted_list;
 pub use format_bulleted_list::format_bulleted_list_multiline;
 pub use git::repository_url_destination;
 pub use git::AddWorktreeOpts;
 pub use git::BranchRef;
+pub use git::BranchStatus;
 pub use git::CommitHash;
 pub use git::Git;
 pub use git::GitBranch;
@@ -44,6 +61,7 @@ pub use git::GitLike;
 pub use git::GitPath;
 pub use git::GitRefs;
 pub use git::GitRemote;
+pub use git::GitSparseCheckout;
 pub use git::GitStatus;
 pub use git::GitWorktree;
 pub use git::HeadKind;
@@ -53,12 +71,18 @@ pub use git::RemoteBranchRef;
 pub use git::RenamedWorktree;
 pub use git::ResolveUniqueNameOpts;
 pub use git::ResolvedCommitish;
+pub use git::SparseCheckout;
 pub use git::Status;
 pub use git::StatusCode;
 pub use git::StatusEntry;
+pub use git::StatusV2;
+pub use git::StatusV2Entry;
+pub use git::SubmoduleStatus;
+pub use git::UrlBranch;
 pub use git::Worktree;
 pub use git::WorktreeHead;
 pub use git::Worktrees;
+pub use json::JSON_SCHEMA_VERSION;
 pub use only_paths_in_parent_directory::only_paths_in_parent_directory;
 pub use path_display::PathDisplay;
 use utf8absolutize::Utf8Absolutize;
@@ -0,0 +1,288 @@
+use std::fmt::Display;
+
+use calm_io::stdout;
+use calm_io::stdoutln;
+use camino::Utf8Path;
+use camino::Utf8PathBuf;
+use itertools::Itertools;
+use miette::IntoDiagnostic;
+use owo_colors::OwoColorize;
+use owo_colors::Stream;
+use serde::Serialize;
+
+use crate::app_git::AppGit;
+use crate::cli::ListArgs;
+use crate::git::GitLike;
+use crate::git::Worktree;
+use crate::git::WorktreeHead;
+use crate::PathDisplay;
+
+pub fn list<C>(git: AppGit<'_, C>, args: &ListArgs) -> miette::Result<()>
+where
+    C: AsRef<Utf8Path>,
+{
+    let worktrees = git.worktree().list()?;
+
+    let listed = worktrees
+        .values()
+        .sorted_by_key(|worktree| (!worktree.is_main, &worktree.path))
+        .map(|worktree| {
+            // Bare worktrees have no working tree, and prunable worktrees may not exist anymore.
+            let dirty = if worktree.head.is_bare() || !worktree.path.exists() {
+                false
+            } else {
+                !git.with_current_dir(&worktree.path)
+                    .status()
+                    .get()?
+                    .is_clean()
+            };
+            Ok((worktree, dirty))
+        })
+        .collect::<miette::Result<Vec<_>>>()?;
+
+    if args.porcelain {
+        // Test: `list_porcelain`
+        for (worktree, dirty) in listed {
+            let dirty = if dirty { "dirty\0" } else { "" };
+            stdout!("{}{dirty}\0", worktree.porcelain()).into_diagnostic()?;
+        }
+    } else {
+        let path_style = if args.absolute {
+            PathStyle::Absolute
+        } else if args.relative {
+            PathStyle::Container(canonicalize(&git.worktree().container()?))
+        } else {
+            PathStyle::Cwd
+        };
+
+        let rows = listed
+            .into_iter()
+            .map(|(worktree, dirty)| {
+                let upstream = match worktree.head.branch() {
+                    Some(branch) => git
+                        .branch()
+                        .upstream(branch.branch_name())?
+                        .map(|upstream| upstream.to_string()),
+                    None => None,
+                };
+                Ok(Row {
+                    worktree,
+                    path: path_style.display(&worktree.path),
+                    head: match &worktree.head {
+                        WorktreeHead::Bare => "bare".to_owned(),
+                        WorktreeHead::Detached(commit) => commit.to_string(),
+                        WorktreeHead::Branch(_, branch) => branch.to_string(),
+                    },
+                    upstream,
+                    dirty,
+                })
+            })
+            .collect::<miette::Result<Vec<_>>>()?;
+
+        if args.json {
+            // Test: `list_json`
+            let json = ListJson {
+                worktrees: rows.iter().map(WorktreeJson::from).collect(),
+            };
+            stdoutln!("{}", crate::json::to_string_pretty(&json)?).into_diagnostic()?;
+        } else {
+            // Test: `list`
+            stdout!("{}", Table(rows)).into_diagnostic()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// How to display worktree paths in `git prole list`.
+enum PathStyle {
+    /// Relative to the current directory, home directory, or `$TMPDIR`, with
+    /// [`PathDisplay::display_path_cwd`].
+    Cwd,
+    /// Canonical absolute paths.
+    Absolute,
+    /// Relative to the (canonical) worktree container.
+    Container(Utf8PathBuf),
+}
+
+impl PathStyle {
+    fn display(&self, path: &Utf8Path) -> String {
+        match self {
+            PathStyle::Cwd => path.display_path_cwd(),
+            // Test: `list_absolute`
+            PathStyle::Absolute => canonicalize(path).into_string(),
+            // Test: `list_relative`
+            PathStyle::Container(container) => {
+                let path = canonicalize(path);
+                match path.strip_prefix(container) {
+                    Ok(relative) => relative.to_string(),
+                    // Worktrees outside of the container are shown in full.
+                    Err(_) => path.into_string(),
+                }
+            }
+        }
+    }
+}
+
+/// Canonicalize a path, if it exists. Prunable worktrees may not.
+fn canonicalize(path: &Utf8Path) -> Utf8PathBuf {
+    path.canonicalize_utf8().unwrap_or_else(|_| path.to_owned())
+}
+
+/// The JSON printed by `git prole list --json`.
+#[derive(Debug, Serialize)]
+struct ListJson<'a> {
+    worktrees: Vec<WorktreeJson<'a>>,
+}
+
+/// A worktree in the JSON printed by `git prole list --json`.
+///
+/// This is separate from [`Worktree`] so that the JSON format doesn't change when the Rust API
+/// does.
+#[derive(Debug, Serialize)]
+struct WorktreeJson<'a> {
+    /// The absolute path to the worktree.
+    path: &'a Utf8Path,
+    /// What's checked out in the worktree.
+    head: HeadKind,
+    /// The full hash of the checked out commit, unless the worktree is bare.
+    commit: Option<String>,
+    /// The checked out branch, if there is one.
+    branch: Option<&'a str>,
+    /// The branch's upstream, if it has one.
+    upstream: Option<&'a str>,
+    /// True for the main worktree.
+    is_main: bool,
+    /// True if the worktree has uncommitted changes.
+    dirty: bool,
+    /// The reason the worktree is locked, if it's locked. This may be empty.
+    locked: Option<&'a str>,
+    /// The reason the worktree is prunable, if it's prunable. This may be empty.
+    prunable: Option<&'a str>,
+}
+
+impl<'a> From<&'a Row<'a>> for WorktreeJson<'a> {
+    fn from(row: &'a Row<'a>) -> Self {
+        let worktree = row.worktree;
+        Self {
+            path: &worktree.path,
+            head: match &worktree.head {
+                WorktreeHead::Bare => HeadKind::Bare,
+                WorktreeHead::Detached(_) => HeadKind::Detached,
+                WorktreeHead::Branch(_, _) => HeadKind::Branch,
+            },
+            commit: worktree.head.commit().map(|commit| format!("{commit:#}")),
+            branch: worktree.head.branch().map(|branch| branch.branch_name()),
+            upstream: row.upstream.as_deref(),
+            is_main: worktree.is_main,
+            dirty: row.dirty,
+            locked: worktree.locked.as_deref(),
+            prunable: worktree.prunable.as_deref(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum HeadKind {
+    Bare,
+    Detached,
+    Branch,
+}
+
+/// A row of the table printed by `git prole list`, before coloring.
+struct Row<'a> {
+    worktree: &'a Worktree,
+    path: String,
+    head: String,
+    upstream: Option<String>,
+    dirty: bool,
+}
+
+impl Row<'_> {
+    /// Format this row with its columns padded to the given widths.
+    ///
+    /// Columns are padded before they're colored, so the alignment is the same whether or not
+    /// colors are enabled.
+    fn format(&self, path_width: usize, head_width: usize, upstream_width: usize) -> String {
+        let head = format!("{:<head_width$}", self.head);
+        let head = if self.worktree.head.is_bare() {
+            head.if_supports_color(Stream::Stdout, |text| text.dimmed())
+                .to_string()
+        } else {
+            head.if_supports_color(Stream::Stdout, |text| text.cyan())
+                .to_string()
+        };
+        let mut line = format!("{:<path_width$}  {head}", self.path);
+
+        // Skip the upstream column entirely if no worktrees have upstreams.
+        if upstream_width > 0 {
+            line.push_str(&format!(
+                "  {}",
+                format!(
+                    "{:<upstream_width$}",
+                    self.upstream.as_deref().unwrap_or_default()
+                )
+                .if_supports_color(Stream::Stdout, |text| text.dimmed())
+            ));
+        }
+
+        if self.dirty {
+            line.push_str(&format!(
+                "  {}",
+                '*'.if_supports_color(Stream::Stdout, |text| text.yellow())
+            ));
+        }
+
+        if self.worktree.is_main {
+            line.push_str(&format!(
+                "  [{}]",
+                "main".if_supports_color(Stream::Stdout, |text| text.cyan())
+            ));
+        }
+
+        if let Some(reason) = &self.worktree.locked {
+            if reason.is_empty() {
+                line.push_str(" (locked)");
+            } else {
+                line.push_str(&format!(" (locked: {reason})"));
+            }
+        }
+
+        if let Some(reason) = &self.worktree.prunable {
+            if reason.is_empty() {
+                line.push_str(" (prunable)");
+            } else {
+                line.push_str(&format!(" (prunable: {reason})"));
+            }
+        }
+
+        // Empty trailing columns leave padding behind.
+        line.truncate(line.trim_end().len());
+        line
+    }
+}
+
+/// Worktrees formatted as a table with aligned path, `HEAD`, and upstream columns, followed by
+/// markers for dirty and main worktrees.
+struct Table<'a>(Vec<Row<'a>>);
+
+impl Display for Table<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = |column: for<'r> fn(&'r Row<'_>) -> &'r str| {
+            self.0
+                .iter()
+                .map(|row| column(row).chars().count())
+                .max()
+                .unwrap_or_default()
+        };
+        let path_width = width(|row| &row.path);
+        let head_width = width(|row| &row.head);
+        let upstream_width = width(|row| row.upstream.as_deref().unwrap_or_default());
+
+        for row in &self.0 {
+            writeln!(f, "{}", row.format(path_width, head_width, upstream_width))?;
+        }
+        Ok(())
+    }
+}
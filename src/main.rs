@@ -1,7 +1,16 @@
+use std::process::ExitCode;
+
 use git_prole::App;
 use git_prole::Config;
+use git_prole::ErrorCode;
 
-fn main() -> miette::Result<()> {
-    let config = Config::new()?;
-    App::new(config).run()
+fn main() -> ExitCode {
+    match Config::new().and_then(|config| App::new(config).run()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(report) => {
+            // Like `miette`'s `Termination` implementation, but with our own exit codes.
+            eprintln!("Error: {report:?}");
+            ErrorCode::of(&report).into()
+        }
+    }
 }
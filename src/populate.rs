@@ -0,0 +1,48 @@
+use camino::Utf8PathBuf;
+use miette::miette;
+
+use crate::add::WorktreePlan;
+use crate::add::WorktreeSetup;
+use crate::app_git::AppGit;
+use crate::cli::PopulateArgs;
+use crate::git::GitLike;
+use crate::PathDisplay;
+
+/// Check out the files in a worktree created with `git prole add --no-checkout`, then set it up
+/// like `git prole add` would have.
+pub fn populate(git: AppGit<'_, Utf8PathBuf>, args: &PopulateArgs) -> miette::Result<()> {
+    let worktrees = git.worktree().list()?;
+    let worktree = worktrees.resolve(&args.worktree)?;
+    let destination = &worktree.path;
+    let worktree_git = git.with_current_dir(destination);
+
+    if worktree.head.is_bare() || !worktree_git.index_is_empty()? {
+        return Err(miette!(
+            "Worktree {} is already checked out",
+            destination.display_path_cwd()
+        ));
+    }
+
+    let git = git.with_current_dir(git.worktree().find_some()?);
+    // Copying a worktree's ignored files onto themselves doesn't make sense.
+    let copy_ignored = if git.get_current_dir() == destination {
+        Vec::new()
+    } else {
+        WorktreePlan::copy_ignored_plan(&git)?
+    };
+
+    tracing::info!("Checking out files in {}", destination.display_path_cwd());
+    if git.config.cli.dry_run {
+        return Ok(());
+    }
+
+    // Test: `add_no_checkout_populate`
+    worktree_git.checkout_head()?;
+    WorktreeSetup {
+        git: &git,
+        destination,
+        copy_ignored: &copy_ignored,
+        copy_ignored_symlinks: git.config.file.add.copy_ignored_symlinks(),
+    }
+    .execute()
+}
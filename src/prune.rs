@@ -0,0 +1,60 @@
+use camino::Utf8Path;
+use command_error::CommandExt;
+use command_error::Utf8ProgramAndArgs;
+use owo_colors::OwoColorize;
+use owo_colors::Stream;
+
+use crate::app_git::AppGit;
+use crate::cli::PruneArgs;
+use crate::format_bulleted_list;
+use crate::git::GitLike;
+use crate::PathDisplay;
+
+pub fn prune<C>(git: AppGit<'_, C>, args: &PruneArgs) -> miette::Result<()>
+where
+    C: AsRef<Utf8Path>,
+{
+    let worktrees = git.worktree().list()?;
+    let prunable = worktrees
+        .values()
+        .filter_map(|worktree| {
+            worktree
+                .prunable
+                .as_ref()
+                .map(|reason| (&worktree.path, reason))
+        })
+        .collect::<Vec<_>>();
+
+    if prunable.is_empty() {
+        // Test: `prune_nothing`
+        tracing::info!("No worktrees to prune");
+        return Ok(());
+    }
+
+    // Test: `prune`
+    tracing::info!(
+        "Pruning worktrees:\n{}",
+        format_bulleted_list(prunable.iter().map(|(path, reason)| {
+            if reason.is_empty() {
+                path.display_path_cwd()
+            } else {
+                // The reason is usually "gitdir file points to non-existent location".
+                format!("{}: {reason}", path.display_path_cwd())
+            }
+        }))
+    );
+
+    let mut command = git.worktree().prune_command(args.expire.as_deref());
+    if git.config.cli.dry_run {
+        // Test: `prune_dry_run`
+        tracing::info!(
+            "{} {}",
+            '$'.if_supports_color(Stream::Stdout, |text| text.green()),
+            Utf8ProgramAndArgs::from(&command)
+        );
+    } else {
+        command.status_checked()?;
+    }
+
+    Ok(())
+}
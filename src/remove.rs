@@ -0,0 +1,79 @@
+use camino::Utf8Path;
+use command_error::CommandExt;
+use command_error::Utf8ProgramAndArgs;
+use miette::miette;
+use owo_colors::OwoColorize;
+use owo_colors::Stream;
+
+use crate::app_git::AppGit;
+use crate::cli::RemoveArgs;
+use crate::git::GitLike;
+use crate::safe_roots::check_safe_roots;
+use crate::PathDisplay;
+
+pub fn remove<C>(git: AppGit<'_, C>, args: &RemoveArgs) -> miette::Result<()>
+where
+    C: AsRef<Utf8Path>,
+{
+    let worktrees = git.worktree().list()?;
+    let worktree = worktrees.resolve(&args.name_or_path)?;
+
+    if worktree.is_main {
+        // Test: `remove_main_worktree`
+        return Err(miette!(
+            "Refusing to remove the main worktree {}",
+            worktree.path.display_path_cwd()
+        ));
+    }
+
+    // Test: `remove_safe_roots`
+    check_safe_roots(
+        &git.config.file,
+        args.allow_outside,
+        [worktree.path.as_path()],
+    )?;
+
+    if !args.force && git.worktree().is_inside()? && git.worktree().root()? == worktree.path {
+        // Test: `remove_current_worktree`
+        return Err(miette!(
+            "Refusing to remove the current worktree {} without `--force`",
+            worktree.path.display_path_cwd()
+        ));
+    }
+
+    // The worktree we're in may be removed, so run commands from the main worktree.
+    let git = git.with_current_dir(worktrees.main_path());
+
+    let mut commands = vec![git.worktree().remove_command(&worktree.path, args.force)];
+    if args.branch {
+        match worktree.head.branch() {
+            Some(branch) => {
+                // Test: `remove_branch`
+                commands.push(git.branch().delete_command(branch));
+            }
+            None => {
+                tracing::warn!(
+                    "{} has no branch to delete",
+                    worktree.path.display_path_cwd()
+                );
+            }
+        }
+    }
+
+    tracing::info!("Removing worktree {}", worktree.path.display_path_cwd());
+
+    for mut command in commands {
+        if git.config.cli.dry_run {
+            // Test: `remove_dry_run`
+            tracing::info!(
+                "{} {}",
+                '$'.if_supports_color(Stream::Stdout, |text| text.green()),
+                Utf8ProgramAndArgs::from(&command)
+            );
+        } else {
+            command.status_checked()?;
+        }
+    }
+
+    Ok(())
+}
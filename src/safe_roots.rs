@@ -0,0 +1,62 @@
+use camino::Utf8Path;
+use camino::Utf8PathBuf;
+use miette::miette;
+use miette::IntoDiagnostic;
+
+use crate::config::ConfigFile;
+use crate::format_bulleted_list;
+use crate::git::expand_home;
+use crate::utf8absolutize::Utf8Absolutize;
+use crate::PathDisplay;
+
+/// Check that each of the `paths` is inside one of the `safe_roots` setting's directories.
+///
+/// If `allow_outside` is true (from `--allow-outside`) or no `safe_roots` are set, every path is
+/// allowed.
+pub fn check_safe_roots<'p>(
+    config: &ConfigFile,
+    allow_outside: bool,
+    paths: impl IntoIterator<Item = &'p Utf8Path>,
+) -> miette::Result<()> {
+    if allow_outside || config.safe_roots().is_empty() {
+        return Ok(());
+    }
+
+    let roots = config
+        .safe_roots()
+        .iter()
+        .map(|root| normalize(&expand_home(root)?))
+        .collect::<miette::Result<Vec<_>>>()?;
+
+    for path in paths {
+        let normal = normalize(path)?;
+        if !roots.iter().any(|root| normal.starts_with(root)) {
+            // Test: `config_safe_roots`
+            return Err(miette!(
+                "Refusing to operate on {} because it's outside of the `safe_roots`; pass \
+                 `--allow-outside` to do it anyways:\n{}",
+                path.display_path_cwd(),
+                format_bulleted_list(roots.iter().map(|root| root.display_path_cwd()))
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Get an absolute path with symlinks resolved.
+///
+/// Paths which don't exist yet (like a `convert` destination) have their closest existing
+/// ancestor canonicalized instead, so they're compared the same way as paths which do exist.
+fn normalize(path: &Utf8Path) -> miette::Result<Utf8PathBuf> {
+    let path = path.absolutize().into_diagnostic()?;
+    for ancestor in path.ancestors() {
+        if let Ok(canonical) = ancestor.canonicalize_utf8() {
+            let rest = path
+                .strip_prefix(ancestor)
+                .expect("A path starts with its ancestors");
+            return Ok(canonical.join(rest));
+        }
+    }
+    Ok(path.into_owned())
+}
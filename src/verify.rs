@@ -0,0 +1,173 @@
+use camino::Utf8Path;
+use camino::Utf8PathBuf;
+use itertools::Itertools;
+use miette::miette;
+
+use crate::app_git::AppGit;
+use crate::cli::VerifyArgs;
+use crate::format_bulleted_list::format_bulleted_list;
+use crate::fs;
+use crate::git::GitLike;
+use crate::PathDisplay;
+
+/// Check that each worktree's `.git` file and its `gitdir` in the common `.git` directory point
+/// at each other.
+///
+/// Broken links are reported, and fixed with `git worktree repair` if `--fix` is given.
+pub fn verify<C>(git: AppGit<'_, C>, args: &VerifyArgs) -> miette::Result<()>
+where
+    C: AsRef<Utf8Path>,
+{
+    let common_dir = canonicalize(&git.path().git_common_dir()?)?;
+    let mut problems = find_problems(&git, &common_dir)?;
+
+    if args.fix && !problems.is_empty() {
+        // Test: `verify_fix`
+        let worktrees = git.worktree().list()?;
+        let mut paths = worktrees
+            .values()
+            .filter(|worktree| !worktree.is_main && worktree.path.exists())
+            .map(|worktree| worktree.path.clone())
+            .collect::<Vec<_>>();
+        paths.extend(find_moved(&common_dir, &git.worktree().container()?)?);
+
+        for path in &paths {
+            tracing::info!("Repairing worktree {}", path.display_path_cwd());
+        }
+        // `git worktree repair` fails if it can't fix every worktree, but it still fixes the rest,
+        // so we check again afterwards to report what's left.
+        if let Err(err) = git.worktree().repair(&paths) {
+            tracing::debug!("{err:?}");
+        }
+
+        problems = find_problems(&git, &common_dir)?;
+    }
+
+    if problems.is_empty() {
+        // Test: `verify`
+        tracing::info!("All {} worktrees are valid", git.worktree().list()?.len());
+        Ok(())
+    } else {
+        // Test: `verify_broken`
+        Err(miette!(
+            "Found broken worktree links:\n{}",
+            format_bulleted_list(problems)
+        ))
+    }
+}
+
+/// Check each linked worktree's links, returning a description of each problem.
+fn find_problems<C>(git: &AppGit<'_, C>, common_dir: &Utf8Path) -> miette::Result<Vec<String>>
+where
+    C: AsRef<Utf8Path>,
+{
+    let worktrees = git.worktree().list()?;
+
+    Ok(worktrees
+        .values()
+        // The main worktree contains the common `.git` directory (or is the common `.git`
+        // directory, if it's bare), so there's no links to check.
+        .filter(|worktree| !worktree.is_main)
+        .sorted_by_key(|worktree| &worktree.path)
+        .filter_map(|worktree| {
+            verify_worktree(common_dir, &worktree.path)
+                .err()
+                .map(|problem| format!("{}: {problem}", worktree.path.display_path_cwd()))
+        })
+        .collect())
+}
+
+/// Find directories in the `container` which look like worktrees of the repository in
+/// `common_dir` but aren't where Git expects them, e.g. because they were moved with `mv`
+/// instead of `git worktree move`.
+fn find_moved(common_dir: &Utf8Path, container: &Utf8Path) -> miette::Result<Vec<Utf8PathBuf>> {
+    let mut moved = Vec::new();
+    for entry in container.read_dir_utf8().map_err(|err| {
+        miette!(
+            "Failed to read worktree container {}: {err}",
+            container.display_path_cwd()
+        )
+    })? {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        let worktree = entry.path();
+        let Ok(gitdir) = read_link_file(&worktree.join(".git"), "gitdir: ") else {
+            continue;
+        };
+        let Ok(gitdir) = canonicalize(&resolve(worktree, &gitdir)) else {
+            continue;
+        };
+        if gitdir.parent() == Some(&common_dir.join("worktrees"))
+            && verify_worktree(common_dir, worktree).is_err()
+        {
+            moved.push(worktree.to_owned());
+        }
+    }
+    moved.sort();
+    Ok(moved)
+}
+
+/// Check a linked worktree's links, returning a description of the problem if they're broken.
+fn verify_worktree(common_dir: &Utf8Path, worktree: &Utf8Path) -> Result<(), String> {
+    if !worktree.exists() {
+        return Err("Worktree directory doesn't exist".to_owned());
+    }
+
+    let dot_git = worktree.join(".git");
+    let gitdir = read_link_file(&dot_git, "gitdir: ")?;
+    let gitdir = resolve(worktree, &gitdir);
+    if !gitdir.exists() {
+        return Err(format!(
+            "`.git` points to {}, which doesn't exist",
+            gitdir.display_path_cwd()
+        ));
+    }
+
+    let gitdir = canonicalize(&gitdir).map_err(|err| err.to_string())?;
+    if gitdir.parent() != Some(&common_dir.join("worktrees")) {
+        return Err(format!(
+            "`.git` points to {}, which isn't in {}",
+            gitdir.display_path_cwd(),
+            common_dir.join("worktrees").display_path_cwd()
+        ));
+    }
+
+    let back_reference = read_link_file(&gitdir.join("gitdir"), "")?;
+    let back_reference = resolve(&gitdir, &back_reference);
+    let points_back = canonicalize(&back_reference)
+        .and_then(|back_reference| Ok(back_reference == canonicalize(&dot_git)?))
+        .unwrap_or(false);
+    if !points_back {
+        return Err(format!(
+            "{} points to {} instead of this worktree",
+            gitdir.join("gitdir").display_path_cwd(),
+            back_reference.display_path_cwd()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Read a path from a file like `.git` or `gitdir`, with the given prefix before the path.
+fn read_link_file(path: &Utf8Path, prefix: &str) -> Result<Utf8PathBuf, String> {
+    let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    contents
+        .strip_prefix(prefix)
+        .map(|link| Utf8PathBuf::from(link.trim_end()))
+        .ok_or_else(|| format!("{} doesn't start with `{prefix}`", path.display_path_cwd()))
+}
+
+/// Resolve a possibly-relative link target relative to `base`.
+fn resolve(base: &Utf8Path, link: &Utf8Path) -> Utf8PathBuf {
+    if link.is_absolute() {
+        link.to_owned()
+    } else {
+        base.join(link)
+    }
+}
+
+fn canonicalize(path: &Utf8Path) -> miette::Result<Utf8PathBuf> {
+    path.canonicalize_utf8()
+        .map_err(|err| miette!("Failed to canonicalize {path}: {err}"))
+}
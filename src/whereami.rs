@@ -0,0 +1,70 @@
+use calm_io::stdoutln;
+use camino::Utf8Path;
+use camino::Utf8PathBuf;
+use miette::IntoDiagnostic;
+use serde::Serialize;
+
+use crate::app_git::AppGit;
+use crate::cli::WhereamiArgs;
+use crate::git::GitLike;
+
+/// Where `git-prole` thinks we are.
+#[derive(Debug, Serialize)]
+struct Whereami {
+    /// The root of the current worktree, if we're in one.
+    worktree: Option<Utf8PathBuf>,
+    /// The worktree container directory.
+    container: Utf8PathBuf,
+    /// The common `.git` directory.
+    git_dir: Utf8PathBuf,
+    /// The checked out branch, if `HEAD` isn't detached.
+    branch: Option<String>,
+}
+
+pub fn whereami<C>(git: AppGit<'_, C>, args: &WhereamiArgs) -> miette::Result<()>
+where
+    C: AsRef<Utf8Path>,
+{
+    let whereami = Whereami {
+        worktree: if git.worktree().is_inside()? {
+            Some(git.worktree().root()?)
+        } else {
+            None
+        },
+        container: git.worktree().container()?,
+        git_dir: git.path().git_common_dir()?,
+        branch: git
+            .branch()
+            .current()?
+            .map(|branch| branch.branch_name().to_owned()),
+    };
+
+    if args.porcelain {
+        // Test: `whereami_porcelain`
+        for (key, value) in [
+            (
+                "worktree",
+                whereami.worktree.as_ref().map(|path| path.as_str()),
+            ),
+            ("container", Some(whereami.container.as_str())),
+            ("git_dir", Some(whereami.git_dir.as_str())),
+            ("branch", whereami.branch.as_deref()),
+        ] {
+            stdoutln!("{key} {}", value.unwrap_or_default()).into_diagnostic()?;
+        }
+    } else if args.json {
+        // Test: `whereami_json_schema_version`
+        stdoutln!("{}", crate::json::to_string_pretty(&whereami)?).into_diagnostic()?;
+    } else {
+        if let Some(worktree) = &whereami.worktree {
+            stdoutln!("worktree {worktree}").into_diagnostic()?;
+        }
+        stdoutln!("container {}", whereami.container).into_diagnostic()?;
+        stdoutln!("git_dir {}", whereami.git_dir).into_diagnostic()?;
+        if let Some(branch) = &whereami.branch {
+            stdoutln!("branch {branch}").into_diagnostic()?;
+        }
+    }
+
+    Ok(())
+}
@@ -38,3 +38,21 @@ pub fn setup_repo_multiple_remotes(
 
     Ok(())
 }
+
+/// Write a fake `program` executable to `bin/{program}` which logs its arguments to
+/// `{program}-log`, and return a `$PATH` value which includes it.
+pub fn setup_fake_program(prole: &GitProle, program: &str) -> miette::Result<String> {
+    prole.sh(&format!(
+        r#"
+        mkdir -p bin
+        printf '#!/bin/sh\necho "$@" >> "$HOME/{program}-log"\n' > bin/{program}
+        chmod +x bin/{program}
+        "#
+    ))?;
+
+    Ok(format!(
+        "{}:{}",
+        prole.path("bin"),
+        std::env::var("PATH").unwrap_or_default()
+    ))
+}
@@ -165,7 +165,7 @@ impl GitProle {
         self.setup_repo(path)?;
         self.cmd()
             .current_dir(self.path(path))
-            .arg("convert")
+            .args(["convert", "--yes"])
             .output_checked_utf8()
             .wrap_err_with(|| format!("Failed to convert {path} to a worktree checkout"))?;
 
@@ -291,8 +291,19 @@ impl WorktreeState {
         self.status = Some(Status {
             entries: entries
                 .into_iter()
-                // lol, lmao
-                .map(|entry| StatusEntry::from_str(&format!("{entry}\0")))
+                .map(|entry| {
+                    // Entries are written like they're displayed, e.g. `R  old -> new`, but
+                    // parsed from the `-z` format, where the new path comes first.
+                    // lol, lmao
+                    let entry = match entry.split_once(" -> ") {
+                        Some((from, to)) => {
+                            let (codes, from) = from.split_at(3);
+                            format!("{codes}{to}\0{from}\0")
+                        }
+                        None => format!("{entry}\0"),
+                    };
+                    StatusEntry::from_str(&entry)
+                })
                 .collect::<Result<Vec<_>, _>>()
                 .expect("All expected status entries parse succesfully"),
         });
@@ -0,0 +1,38 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_bare_no_worktrees() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-remote/my-repo")?;
+
+    // A bare repository made by hand, with no worktrees.
+    prole.sh("
+        mkdir my-repo
+        cd my-repo || exit
+        git init --bare .git
+        cd .git || exit
+        git fetch ../../my-remote/my-repo main:main
+        ")?;
+
+    let output = prole
+        .cd_cmd("my-repo")
+        .args(["add", "main"])
+        .output_checked_utf8()?;
+    assert!(
+        output.stderr.contains("has no worktrees yet"),
+        "`add` should mention that it's creating the first worktree: {}",
+        output.stderr
+    );
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
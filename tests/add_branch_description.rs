@@ -0,0 +1,42 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_branch_description() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "-b", "puppy", "--description", "A very good branch"])
+        .status_checked()?;
+
+    assert_eq!(
+        prole
+            .git("my-repo/puppy")
+            .config()
+            .get("branch.puppy.description")?,
+        Some("A very good branch".to_owned())
+    );
+
+    // Descriptions are only for new branches.
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--detach", "doggy", "--description", "Not a branch"])
+        .status_checked()
+        .unwrap_err();
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy").upstream("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
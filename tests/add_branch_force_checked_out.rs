@@ -0,0 +1,37 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_branch_force_checked_out() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    // `puppy` is checked out in `my-repo/puppy`, so we refuse to reset it.
+    let error = prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "-B", "puppy", "doggy"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("is checked out in worktree"),
+        "`-B` should refuse to reset a branch checked out elsewhere: {error}"
+    );
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_branch_from_tag() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.sh(r#"
+        cd my-repo/main || exit
+        echo "puppy v2!" > README.md
+        git commit -am "Release v2.0.0"
+        git tag --annotate --message "Version 2.0.0" v2.0.0
+        git reset --hard HEAD^
+        # Tags can't be tracked, even if Git would otherwise try.
+        git config branch.autoSetupMerge always
+    "#)?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--branch", "release-2", "release-2", "v2.0.0"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("release-2")
+                .branch("release-2")
+                .no_upstream()
+                .commit("417356cd")
+                .file(
+                    "README.md",
+                    expect![[r#"
+                        puppy v2!
+                    "#]],
+                ),
+        ])
+        .assert();
+
+    Ok(())
+}
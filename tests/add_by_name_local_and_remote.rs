@@ -0,0 +1,59 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_by_name_local_and_remote() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-remote/my-repo")?;
+    // Set up a `puppy` branch in the remote.
+    prole.sh("
+        cd my-remote/my-repo || exit
+        git switch -c puppy
+        echo 'softy pup' > README.md
+        git commit -am 'cooler readme'
+        git switch main
+        ")?;
+
+    prole
+        .cmd()
+        .args(["clone", "my-remote/my-repo"])
+        .status_checked()?;
+
+    // Set up a local `puppy` branch which has diverged from `origin/puppy`.
+    prole.sh("
+        cd my-repo/main || exit
+        git branch puppy
+        ")?;
+
+    let output = prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .output_checked_utf8()?;
+    assert!(
+        output.stderr.contains("point to different"),
+        "`add` should warn that the local and remote branches differ: {}",
+        output.stderr
+    );
+
+    // The local branch is checked out.
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .no_upstream()
+                .file(
+                    "README.md",
+                    expect![[r#"
+                        puppy doggy
+                    "#]],
+                ),
+        ])
+        .assert();
+
+    Ok(())
+}
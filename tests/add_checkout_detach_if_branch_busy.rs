@@ -0,0 +1,42 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_checkout_detach_if_branch_busy() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.sh("
+        cd my-repo/main || exit
+        git switch -c puppy
+        echo 'softie cutie' > README.md
+        git commit -am 'Softer puppy'
+        ")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--checkout-detach-if-branch-busy", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main")
+                .branch("puppy")
+                .commit("39f50a15"),
+            WorktreeState::new("puppy-detached")
+                .detached("39f50a15")
+                .file(
+                    "README.md",
+                    expect![[r#"
+                        softie cutie
+                    "#]],
+                ),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_container_relative() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.sh("
+        mkdir -p my-repo/main/src/nested
+        ")?;
+
+    prole
+        .cd_cmd("my-repo/main/src/nested")
+        .args(["add", "@/experiments/puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("experiments/puppy")
+                .branch("puppy")
+                .upstream("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_copy_ignored_symlinks() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        copy_ignored_symlinks = false
+        "#,
+    )?;
+
+    prole.sh(r#"
+        cd my-repo/main || exit
+
+        echo "my-cool-symlink" >> .gitignore
+        echo "my-cool-file" >> .gitignore
+        git add .gitignore
+        git commit -m "Add .gitignore"
+
+        ln -s my-cool-file my-cool-symlink
+        echo "doggy" > my-cool-file
+        "#)?;
+
+    // `--copy-ignored-symlinks` overrides the setting.
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--copy-ignored-symlinks", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main")
+                .branch("main")
+                .status(["!! my-cool-file", "!! my-cool-symlink"]),
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .upstream("main")
+                .file(
+                    "my-cool-file",
+                    expect![[r#"
+                        doggy
+                    "#]],
+                )
+                .status(["!! my-cool-file", "!! my-cool-symlink"]),
+        ])
+        .assert();
+
+    Ok(())
+}
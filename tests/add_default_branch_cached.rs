@@ -0,0 +1,56 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_default_branch_cached() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("remote/my-repo")?;
+    prole
+        .cmd()
+        .args(["clone", "remote/my-repo"])
+        .status_checked()?;
+
+    // Without `origin/HEAD`, we have to ask the remote for its default branch.
+    prole.sh("
+        cd my-repo/main || exit
+        git remote set-head origin --delete
+        ")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    assert!(prole
+        .path(".local/state/git-prole/default-branches.toml")
+        .exists());
+
+    // Now we can't write `origin/HEAD` or ask the remote, but the default branch is still cached.
+    prole.sh("
+        cd my-repo/main || exit
+        git remote set-head origin --delete
+        mv ../../remote ../../remote-gone
+        ")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "doggy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .upstream("origin/main"),
+            WorktreeState::new("doggy")
+                .branch("doggy")
+                .upstream("origin/main"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_detached_tag() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.sh(r#"
+        cd my-repo/main || exit
+        echo "puppy v2!" > README.md
+        git commit -am "Release v2.0.0"
+        git tag v2.0.0
+        git reset --hard HEAD^
+    "#)?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--detach", "v2.0.0"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("v2.0.0").detached("417356cd").file(
+                "README.md",
+                expect![[r#"
+                    puppy v2!
+                "#]],
+            ),
+        ])
+        .assert();
+
+    Ok(())
+}
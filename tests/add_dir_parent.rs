@@ -0,0 +1,30 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_dir_parent() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    for dir in ["..", "../../elsewhere"] {
+        let error = prole
+            .cd_cmd("my-repo/main")
+            .args(["add", "-b", "puppy", "--dir", dir])
+            .output_checked_utf8()
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("`--dir`"), "{error}");
+    }
+
+    // No worktree is created outside the container.
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
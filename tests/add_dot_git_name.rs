@@ -0,0 +1,39 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_dot_git_name() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    for args in [
+        &["add", ".gitfoo"][..],
+        &["add", ".git"],
+        &["add", "-b", "puppy", "../.git-puppy"],
+        &["add", "--dir", ".git-doggy", "-b", "doggy"],
+    ] {
+        let error = prole
+            .cd_cmd("my-repo/main")
+            .args(args)
+            .output_checked_utf8()
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("Worktree directory names can't start with `.git`")
+                && error.contains("--dir NAME"),
+            "{args:?} should be rejected: {error}"
+        );
+    }
+
+    // Nothing was created.
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
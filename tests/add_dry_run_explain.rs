@@ -0,0 +1,45 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_dry_run_explain() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-remote/my-repo")?;
+    prole.sh("
+        cd my-remote/my-repo || exit
+        git branch bar
+        ")?;
+
+    prole
+        .cmd()
+        .args(["clone", "my-remote/my-repo"])
+        .status_checked()?;
+
+    let output = prole
+        .cd_cmd("my-repo/main")
+        .args(["--dry-run", "add", "foo", "bar"])
+        .output_checked_utf8()?;
+
+    assert!(
+        output.stderr.contains("NAME_OR_PATH=foo"),
+        "The arguments should be explained: {}",
+        output.stderr
+    );
+    assert!(
+        output.stderr.contains("remote branch origin/bar"),
+        "The start point should be explained: {}",
+        output.stderr
+    );
+
+    // Nothing is created.
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
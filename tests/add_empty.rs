@@ -0,0 +1,46 @@
+use command_error::CommandExt;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_empty() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.sh(r#"
+        mkdir -p my-repo/.git/hooks
+        echo 'echo pre-commit >> "$HOME/hook-log"' > my-repo/.git/hooks/pre-commit
+        chmod +x my-repo/.git/hooks/pre-commit
+        "#)?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--empty", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy").upstream("main"),
+        ])
+        .assert();
+
+    let git = prole.git("my-repo/puppy");
+    let log = git
+        .command()
+        .args(["log", "--format=%s", "main..puppy"])
+        .output_checked_utf8()?
+        .stdout;
+    assert_eq!(log, "Start puppy\n");
+    git.command()
+        .args(["diff", "--exit-code", "main", "puppy"])
+        .status_checked()?;
+
+    // Hooks run for the empty commit.
+    assert_eq!(prole.contents("hook-log")?, "pre-commit\n");
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_fetch() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+    prole.setup_repo("fork")?;
+
+    prole.sh("
+        cd fork || exit
+        git switch -c their-branch
+        echo 'softie cutie' > README.md
+        git commit -am 'Softer'
+        ")?;
+
+    let fork = prole.path("fork").canonicalize_utf8().unwrap();
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "review", "--fetch", &format!("{fork}:their-branch")])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("review").branch("review").no_upstream(),
+        ])
+        .assert();
+
+    assert_eq!(
+        prole.git("my-repo/review").refs().get_head()?,
+        prole.git("fork").refs().get_head()?,
+    );
+
+    // No remote is added.
+    assert_eq!(prole.git("my-repo").remote().list()?, Vec::<String>::new());
+
+    Ok(())
+}
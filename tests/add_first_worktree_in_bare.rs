@@ -0,0 +1,45 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_first_worktree_in_bare() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-remote/my-repo")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        commands = [
+            "sh -c 'echo Puppy wuz here > puppy-log'",
+        ]
+        "#,
+    )?;
+
+    // A bare repository with no worktrees at all.
+    prole.sh("
+        mkdir my-repo
+        git clone --bare my-remote/my-repo my-repo/.git
+        ")?;
+
+    prole
+        .cd_cmd("my-repo/.git")
+        .args(["add", "main"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main").file(
+                "puppy-log",
+                expect![[r#"
+                    Puppy wuz here
+                "#]],
+            ),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_from_head_of() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.sh("
+        cd my-repo/main || exit
+        git worktree add ../puppy
+        cd ../puppy || exit
+        echo 'softie cutie' > README.md
+        git commit -am 'Softer'
+        ")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--from-head-of", "puppy", "-b", "doggy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy"),
+            WorktreeState::new("doggy").branch("doggy"),
+        ])
+        .assert();
+
+    assert_eq!(
+        prole.git("my-repo/doggy").refs().get_head()?,
+        prole.git("my-repo/puppy").refs().get_head()?,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_hooks_dir() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--hooks-dir", ".githooks", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy"),
+        ])
+        .assert();
+
+    assert_eq!(
+        prole
+            .git("my-repo/puppy")
+            .config()
+            .get("core.hooksPath")?
+            .as_deref(),
+        Some(".githooks")
+    );
+    // The setting is only for the new worktree.
+    assert_eq!(
+        prole.git("my-repo/main").config().get("core.hooksPath")?,
+        None
+    );
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_inherit_remote() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-remote/my-repo")?;
+
+    prole
+        .cmd()
+        .args(["clone", "my-remote/my-repo"])
+        .status_checked()?;
+
+    prole.sh("
+        git clone my-remote/my-repo my-fork/my-repo
+        cd my-fork/my-repo || exit
+        echo 'forked pup' > README.md
+        git commit -am 'Fork readme'
+        cd ../../my-repo/main || exit
+        git remote add fork ../../my-fork/my-repo
+        git fetch fork
+        git worktree add -b forky ../forky fork/main
+        ")?;
+
+    prole
+        .cd_cmd("my-repo/forky")
+        .args(["add", "--inherit-remote", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main")
+                .branch("main")
+                .upstream("origin/main"),
+            WorktreeState::new("forky")
+                .branch("forky")
+                .upstream("fork/main"),
+            // The new branch tracks the same remote as the current one.
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .upstream("fork/main")
+                .file(
+                    "README.md",
+                    expect![[r#"
+                        forked pup
+                    "#]],
+                ),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_inherit_sparse() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+    prole.sh(r#"
+        cd my-repo || exit
+        mkdir -p puppy/src doggy/src
+        echo "cute" > puppy/src/lib.rs
+        echo "silly" > doggy/src/lib.rs
+        git add .
+        git commit -m "Add crates"
+        "#)?;
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
+
+    // Cone mode.
+    prole.sh(r#"
+        cd my-repo/main || exit
+        git sparse-checkout set --cone puppy
+        "#)?;
+    // `git sparse-checkout` moves `core.bare` into the main worktree's config, so Git no longer
+    // reports the main worktree as bare from other worktrees. Give explicit paths so that the
+    // worktree container doesn't matter.
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--inherit-sparse", "../cone"])
+        .status_checked()?;
+
+    // Non-cone mode.
+    prole.sh(r#"
+        cd my-repo/main || exit
+        git sparse-checkout set --no-cone '/doggy/src/'
+        "#)?;
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--inherit-sparse", "../no-cone"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("cone")
+                .branch("cone")
+                .file("README.md", expect_test::expect!["puppy doggy\n"])
+                .file("puppy/src/lib.rs", expect_test::expect!["cute\n"])
+                .no_file("doggy/src/lib.rs"),
+            WorktreeState::new("no-cone")
+                .branch("no-cone")
+                .no_file("README.md")
+                .no_file("puppy/src/lib.rs")
+                .file("doggy/src/lib.rs", expect_test::expect!["silly\n"]),
+        ])
+        .assert();
+
+    let cone = prole.git("my-repo/cone").sparse_checkout().get()?.unwrap();
+    assert!(cone.cone);
+    assert_eq!(cone.patterns, vec!["puppy"]);
+
+    let no_cone = prole
+        .git("my-repo/no-cone")
+        .sparse_checkout()
+        .get()?
+        .unwrap();
+    assert!(!no_cone.cone);
+    assert_eq!(no_cone.patterns, vec!["/doggy/src/"]);
+
+    Ok(())
+}
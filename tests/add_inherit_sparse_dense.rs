@@ -0,0 +1,39 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_inherit_sparse_dense() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    let output = prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--inherit-sparse", "puppy"])
+        .output_checked_utf8()?;
+    assert!(
+        output.stderr.contains("doesn't use sparse-checkout"),
+        "`--inherit-sparse` should warn when the current worktree isn't sparse: {}",
+        output.stderr
+    );
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .file("README.md", expect_test::expect!["puppy doggy\n"]),
+        ])
+        .assert();
+
+    assert!(prole
+        .git("my-repo/puppy")
+        .sparse_checkout()
+        .get()?
+        .is_none());
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::setup_fake_program;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_issue() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+    let path = setup_fake_program(&prole, "gh")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--issue", "42"])
+        .env("PATH", path)
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("issue-42")
+                .branch("issue-42")
+                .upstream("main"),
+        ])
+        .assert();
+
+    expect![[r#"
+        issue view 42 --json title --jq .title
+    "#]]
+    .assert_eq(&prole.contents("gh-log")?);
+
+    Ok(())
+}
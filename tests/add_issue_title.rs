@@ -0,0 +1,34 @@
+use command_error::CommandExt;
+use test_harness::setup_fake_program;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_issue_title() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+    let path = setup_fake_program(&prole, "gh")?;
+
+    prole.sh(r#"
+        printf '#!/bin/sh\necho "Fix the puppy bed!"\n' > bin/gh
+        "#)?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--issue", "42"])
+        .env("PATH", path)
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("issue-42-fix-the-puppy-bed")
+                .branch("issue-42-fix-the-puppy-bed")
+                .upstream("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
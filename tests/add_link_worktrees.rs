@@ -0,0 +1,63 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_link_worktrees() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--link-worktrees", "puppy"])
+        .status_checked()?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--link-worktrees", "doggy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            // The links are ignored, so they don't show up as untracked files.
+            WorktreeState::new("main")
+                .branch("main")
+                .status(["!! .worktrees/"]),
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .status(["!! .worktrees/"]),
+            WorktreeState::new("doggy")
+                .branch("doggy")
+                .status(["!! .worktrees/"]),
+        ])
+        .assert();
+
+    for (worktree, siblings) in [
+        ("main", ["puppy", "doggy"]),
+        ("puppy", ["main", "doggy"]),
+        ("doggy", ["main", "puppy"]),
+    ] {
+        assert!(!prole
+            .path(&format!("my-repo/{worktree}/.worktrees/{worktree}"))
+            .exists());
+        for sibling in siblings {
+            let link = prole.path(&format!("my-repo/{worktree}/.worktrees/{sibling}"));
+            assert_eq!(
+                link.read_link_utf8().unwrap(),
+                format!("../../{sibling}"),
+                "{link} should link to {sibling}"
+            );
+            assert_eq!(
+                link.canonicalize_utf8().unwrap(),
+                prole
+                    .path(&format!("my-repo/{sibling}"))
+                    .canonicalize_utf8()
+                    .unwrap(),
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_link_worktrees_nested() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--link-worktrees", "-b", "feature/x", "@/feature/x"])
+        .status_checked()?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--link-worktrees", "-b", "bugfix/x", "@/bugfix/x"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main")
+                .branch("main")
+                .status(["!! .worktrees/"]),
+            WorktreeState::new("feature/x")
+                .branch("feature/x")
+                .upstream("main")
+                .status(["!! .worktrees/"]),
+            WorktreeState::new("bugfix/x")
+                .branch("bugfix/x")
+                .upstream("main")
+                .status(["!! .worktrees/"]),
+        ])
+        .assert();
+
+    // Worktrees with the same directory name get separate links.
+    for (worktree, siblings) in [
+        ("main", ["feature/x", "bugfix/x"]),
+        ("feature/x", ["main", "bugfix/x"]),
+        ("bugfix/x", ["main", "feature/x"]),
+    ] {
+        for sibling in siblings {
+            let link = prole.path(&format!("my-repo/{worktree}/.worktrees/{sibling}"));
+            assert_eq!(
+                link.canonicalize_utf8().unwrap(),
+                prole
+                    .path(&format!("my-repo/{sibling}"))
+                    .canonicalize_utf8()
+                    .unwrap(),
+                "{link} should link to {sibling}"
+            );
+        }
+    }
+
+    Ok(())
+}
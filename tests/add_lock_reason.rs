@@ -0,0 +1,35 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use git_prole::LocalBranchRef;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn add_lock_reason() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--lock-reason", "on a usb stick", "puppy"])
+        .status_checked()?;
+
+    let worktrees = prole.git("my-repo").worktree().list()?;
+    assert_eq!(
+        worktrees
+            .for_branch(&LocalBranchRef::new("puppy".to_owned()))
+            .unwrap()
+            .locked
+            .as_deref(),
+        Some("on a usb stick")
+    );
+    assert_eq!(
+        worktrees
+            .for_branch(&LocalBranchRef::new("main".to_owned()))
+            .unwrap()
+            .locked,
+        None
+    );
+
+    Ok(())
+}
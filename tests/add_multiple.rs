@@ -0,0 +1,27 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_multiple() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--multiple", "puppy", "doggy", "softy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy").upstream("main"),
+            WorktreeState::new("doggy").branch("doggy").upstream("main"),
+            WorktreeState::new("softy").branch("softy").upstream("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_multiple_keep_going() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.sh(r#"
+        mkdir my-repo/doggy
+        "#)?;
+
+    // Without `--keep-going`, we stop at the first failure.
+    let error = prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--multiple", "doggy", "puppy"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("Failed to add worktree doggy"),
+        "The failed worktree is reported: {error}"
+    );
+    assert!(!prole.path("my-repo/puppy").exists());
+
+    let error = prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--multiple", "--keep-going", "doggy", "puppy"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("Failed to add 1 of 2 worktrees"),
+        "The failures are summarized: {error}"
+    );
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy").upstream("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
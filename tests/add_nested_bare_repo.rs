@@ -0,0 +1,58 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_nested_bare_repo() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        branch_names = []
+        "#,
+    )?;
+
+    prole.sh(r#"
+        cd my-repo || exit
+        git worktree move main puppy
+        cd puppy || exit
+        git switch -c puppy
+        git branch -D main
+
+        echo 'puppy-file' > .gitignore
+        echo 'vendor/' >> .gitignore
+        git add .gitignore
+        git commit -m 'Add .gitignore'
+
+        echo puppyyyy > puppy-file
+
+        # An unrelated bare repository nested inside a worktree.
+        git init --bare vendor/other.git
+        # And another one next to the worktrees.
+        git init --bare ../other.git
+    "#)?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["add", "doggy", "@"])
+        .status_checked()?;
+
+    // The nested bare repositories aren't mistaken for worktrees.
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("puppy").branch("puppy"),
+            WorktreeState::new("doggy").branch("doggy").file(
+                "puppy-file",
+                expect![[r#"
+                    puppyyyy
+                "#]],
+            ),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_no_checkout_populate() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        commands = [
+            "sh -c 'echo Puppy wuz here > puppy-log'",
+        ]
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--no-checkout", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .no_file("README.md")
+                .no_file("puppy-log"),
+        ])
+        .assert();
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["populate", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .file("README.md", expect!["puppy doggy\n"])
+                .file("puppy-log", expect!["Puppy wuz here\n"]),
+        ])
+        .assert();
+
+    let error = prole
+        .cd_cmd("my-repo/main")
+        .args(["populate", "puppy"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("is already checked out"),
+        "Populating a checked-out worktree should fail: {error}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_no_checkout_populate_prefix() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--no-checkout", "-b", "feature/puppy"])
+        .status_checked()?;
+
+    // Worktrees are found the same way as for `git prole cd`.
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["populate", "feat"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy")
+                .branch("feature/puppy")
+                .upstream("main")
+                .file("README.md", expect!["puppy doggy\n"]),
+        ])
+        .assert();
+
+    Ok(())
+}
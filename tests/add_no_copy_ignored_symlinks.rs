@@ -0,0 +1,71 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_no_copy_ignored_symlinks() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.sh(r#"
+        cd my-repo/main || exit
+
+        echo "my-cool-symlink" >> .gitignore
+        echo "untracked-dir" >> .gitignore
+        echo "my-cool-file" >> .gitignore
+        git add .gitignore
+        git commit -m "Add .gitignore"
+
+        ln -s does-not-exist my-cool-symlink
+        mkdir untracked-dir
+        ln -s does-not-exist untracked-dir/my-cooler-symlink
+        echo "puppy" > untracked-dir/my-cooler-file
+        echo "doggy" > my-cool-file
+        "#)?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--no-copy-ignored-symlinks", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main").status([
+                "!! my-cool-file",
+                "!! my-cool-symlink",
+                "!! untracked-dir/",
+            ]),
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .upstream("main")
+                .file(
+                    "my-cool-file",
+                    expect![[r#"
+                        doggy
+                    "#]],
+                )
+                .file(
+                    "untracked-dir/my-cooler-file",
+                    expect![[r#"
+                        puppy
+                    "#]],
+                )
+                .status(["!! my-cool-file", "!! untracked-dir/"]),
+        ])
+        .assert();
+
+    assert!(prole
+        .path("my-repo/puppy/my-cool-symlink")
+        .symlink_metadata()
+        .is_err());
+    assert!(prole
+        .path("my-repo/puppy/untracked-dir/my-cooler-symlink")
+        .symlink_metadata()
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_no_link_worktrees() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        link_siblings = true
+        "#,
+    )?;
+
+    // `--no-link-worktrees` overrides the setting.
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--no-link-worktrees", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main").status([]),
+            WorktreeState::new("puppy").branch("puppy").status([]),
+        ])
+        .assert();
+
+    assert!(!prole.path("my-repo/main/.worktrees").exists());
+    assert!(!prole.path("my-repo/puppy/.worktrees").exists());
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use std::process::Stdio;
+
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_pick_base_not_a_tty() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    let error = prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--pick-base", "puppy"])
+        .stdin(Stdio::null())
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("needs an interactive terminal"),
+        "`--pick-base` should fail without a terminal: {error}"
+    );
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
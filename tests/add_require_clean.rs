@@ -0,0 +1,40 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn add_require_clean() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        commands = [
+            "sh -c 'echo Puppy wuz here >> README.md'",
+        ]
+        "#,
+    )?;
+
+    // Without `--require-clean`, a dirty worktree is fine.
+    prole
+        .cd_cmd("my-repo")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    let error = prole
+        .cd_cmd("my-repo")
+        .args(["add", "--require-clean", "doggy"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("has modified files after setup"),
+        "`--require-clean` should fail for a dirty worktree: {error}"
+    );
+    assert!(
+        error.contains("README.md"),
+        "The modified files should be listed: {error}"
+    );
+
+    Ok(())
+}
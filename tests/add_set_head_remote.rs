@@ -0,0 +1,66 @@
+use command_error::CommandExt;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_set_head_remote() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("remote/my-repo")?;
+    prole
+        .cmd()
+        .args(["clone", "remote/my-repo"])
+        .status_checked()?;
+
+    prole.sh("
+        cd my-repo/main || exit
+        git remote set-head origin --delete
+        ")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--set-head-remote", "puppy"])
+        .status_checked()?;
+
+    assert_eq!(
+        prole
+            .git("my-repo/main")
+            .command()
+            .args(["symbolic-ref", "refs/remotes/origin/HEAD"])
+            .output_checked_utf8()?
+            .stdout
+            .trim(),
+        "refs/remotes/origin/main"
+    );
+
+    // `origin/HEAD` was written before the default branch was needed, so the cache wasn't used.
+    assert!(!prole
+        .path(".local/state/git-prole/default-branches.toml")
+        .exists());
+
+    // Without the remote, the default branch is still found from `origin/HEAD`.
+    prole.sh("
+        mv remote remote-gone
+        ")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "doggy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .upstream("origin/main"),
+            WorktreeState::new("doggy")
+                .branch("doggy")
+                .upstream("origin/main"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::setup_fake_program;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_tmux() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+    let path = setup_fake_program(&prole, "tmux")?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["add", "--tmux", "-b", "doggy/puppy"])
+        .env("PATH", path)
+        .env("TMUX", "/tmp/tmux-1000/default,1234,0")
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("doggy/puppy"),
+        ])
+        .assert();
+
+    let root = prole.path("").canonicalize_utf8().unwrap();
+    let log = prole.contents("tmux-log")?.replace(root.as_str(), "$ROOT");
+    expect![[r#"
+        new-window -n puppy -c $ROOT/my-repo/puppy
+    "#]]
+    .assert_eq(&log);
+
+    Ok(())
+}
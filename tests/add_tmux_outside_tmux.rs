@@ -0,0 +1,31 @@
+use command_error::CommandExt;
+use test_harness::setup_fake_program;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_tmux_outside_tmux() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+    let path = setup_fake_program(&prole, "tmux")?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["add", "--tmux", "puppy"])
+        .env("PATH", path)
+        .env_remove("TMUX")
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy"),
+        ])
+        .assert();
+
+    assert!(!prole.path("tmux-log").exists());
+
+    Ok(())
+}
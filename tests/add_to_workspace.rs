@@ -0,0 +1,50 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_to_workspace() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+    prole.sh(r#"
+        echo '{"folders": [{"path": "main"}]}' > my-repo/my-repo.code-workspace
+        "#)?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args([
+            "add",
+            "--add-to-workspace",
+            "../my-repo.code-workspace",
+            "puppy",
+        ])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy"),
+        ])
+        .assert();
+
+    prole.assert_contents(&[(
+        "my-repo/my-repo.code-workspace",
+        expect![[r#"
+            {
+              "folders": [
+                {
+                  "path": "main"
+                },
+                {
+                  "path": "puppy"
+                }
+              ]
+            }
+        "#]],
+    )]);
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_track_remote_only() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-remote/my-repo")?;
+    // Set up a `puppy` branch in the remote.
+    prole.sh("
+        cd my-remote/my-repo || exit
+        git switch -c puppy
+        echo 'softy pup' > README.md
+        git commit -am 'cooler readme'
+        git switch main
+        ")?;
+
+    prole
+        .cmd()
+        .args(["clone", "my-remote/my-repo"])
+        .status_checked()?;
+
+    // Oops, a typo!
+    let error = prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--track-remote-only", "pupy"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("wasn't found on any remote"),
+        "`--track-remote-only` should refuse to create a new branch: {error}"
+    );
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--track-remote-only", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .upstream("origin/puppy")
+                .file(
+                    "README.md",
+                    expect![[r#"
+                        softy pup
+                    "#]],
+                ),
+        ])
+        .assert();
+
+    Ok(())
+}
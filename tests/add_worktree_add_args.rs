@@ -0,0 +1,47 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use git_prole::LocalBranchRef;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn add_worktree_add_args() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy", "--", "--lock"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy"),
+        ])
+        .assert();
+
+    assert!(prole
+        .git("my-repo")
+        .worktree()
+        .list()?
+        .for_branch(&LocalBranchRef::new("puppy".to_owned()))
+        .unwrap()
+        .locked
+        .is_some());
+
+    // Arguments `git prole` manages itself produce a warning.
+    let output = prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "doggy", "--", "--no-track"])
+        .output_checked_utf8()?;
+    assert!(
+        output.stderr.contains("may conflict"),
+        "Conflicting `git worktree add` arguments should be warned about: {}",
+        output.stderr
+    );
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use command_error::CommandExt;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn cd() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    let stdout = prole
+        .cd_cmd("my-repo/main")
+        .args(["cd", "puppy"])
+        .output_checked_utf8()?
+        .stdout;
+
+    // Only the path is printed.
+    assert_eq!(
+        stdout,
+        format!(
+            "{}\n",
+            prole.path("my-repo/puppy").canonicalize_utf8().unwrap()
+        )
+    );
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use command_error::CommandExt;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn cd_branch_with_slash() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "-b", "feature/puppy", "puppy"])
+        .status_checked()?;
+
+    let stdout = prole
+        .cd_cmd("my-repo/main")
+        .args(["cd", "feature/puppy"])
+        .output_checked_utf8()?
+        .stdout;
+
+    assert_eq!(
+        stdout,
+        format!(
+            "{}\n",
+            prole.path("my-repo/puppy").canonicalize_utf8().unwrap()
+        )
+    );
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use command_error::CommandExt;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn cd_default() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    // From the container, the default branch's worktree is used.
+    let stdout = prole
+        .cd_cmd("my-repo")
+        .arg("cd")
+        .output_checked_utf8()?
+        .stdout;
+
+    assert_eq!(
+        stdout,
+        format!(
+            "{}\n",
+            prole.path("my-repo/main").canonicalize_utf8().unwrap()
+        )
+    );
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn cd_not_found() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    let output = prole
+        .cd_cmd("my-repo/main")
+        .args(["cd", "puppy"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        output.contains("No worktree found named puppy"),
+        "A missing worktree is reported: {output}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use command_error::CommandExt;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn cd_prefix() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "-b", "feature/login", "login"])
+        .status_checked()?;
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "-b", "feature/logout", "logout"])
+        .status_checked()?;
+
+    let stdout = prole
+        .cd_cmd("my-repo/main")
+        .args(["cd", "feature/logi"])
+        .output_checked_utf8()?
+        .stdout;
+
+    assert_eq!(
+        stdout,
+        format!(
+            "{}\n",
+            prole.path("my-repo/login").canonicalize_utf8().unwrap()
+        )
+    );
+
+    let error = prole
+        .cd_cmd("my-repo/main")
+        .args(["cd", "feat"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        error.contains("Multiple worktrees match feat:"),
+        "Ambiguous prefixes are an error: {error}"
+    );
+
+    Ok(())
+}
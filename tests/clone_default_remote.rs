@@ -0,0 +1,25 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn clone_default_remote() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("remote/my-repo")?;
+
+    prole
+        .cmd()
+        .args(["clone", "--default-remote", "origin", "remote/my-repo"])
+        .status_checked()?;
+
+    assert_eq!(
+        prole
+            .git("my-repo/main")
+            .config()
+            .get("checkout.defaultRemote")?,
+        Some("origin".to_owned())
+    );
+
+    Ok(())
+}
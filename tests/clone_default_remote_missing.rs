@@ -0,0 +1,21 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn clone_default_remote_missing() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("remote/my-repo")?;
+
+    let output = prole
+        .cmd()
+        .args(["clone", "--default-remote", "upstream", "remote/my-repo"])
+        .output_checked_utf8()?;
+
+    assert!(
+        output.stderr.contains("doesn't exist"),
+        "A missing default remote should be warned about: {}",
+        output.stderr
+    );
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::setup_fake_program;
+use test_harness::GitProle;
+
+#[test]
+fn clone_destination_mismatch() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    // A fake `gh` which doesn't actually clone anything.
+    let path = setup_fake_program(&prole, "gh")?;
+    prole.write_config(
+        r#"
+        [clone]
+        enable_gh = true
+        "#,
+    )?;
+
+    // The destination is inside another repository, which we must not convert.
+    prole.sh("
+        git init --quiet
+        mkdir doggy
+        echo 'not a clone' > doggy/README.md
+        ")?;
+
+    let error = prole
+        .cmd()
+        .args(["clone", "puppy/doggy"])
+        .env("PATH", path)
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("but it's in"),
+        "`clone` should notice the repository wasn't cloned into the destination: {error}"
+    );
+
+    prole.assert_contents(&[(
+        "doggy/README.md",
+        expect![[r#"
+            not a clone
+        "#]],
+    )]);
+    assert!(!prole.path(".git/worktrees").exists());
+
+    Ok(())
+}
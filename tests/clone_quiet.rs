@@ -0,0 +1,41 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn clone_quiet() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("remote/my-repo")?;
+
+    let output = prole
+        .cmd()
+        .args(["clone", "remote/my-repo"])
+        .output_checked_utf8()
+        .unwrap();
+    assert!(
+        output.stderr.contains("Cloning into"),
+        "`git clone` is not quiet by default: {}",
+        output.stderr
+    );
+
+    let output = prole
+        .cmd()
+        .args(["-q", "clone", "remote/my-repo", "my-repo-quiet"])
+        .output_checked_utf8()
+        .unwrap();
+    assert!(
+        !output.stderr.contains("Cloning into"),
+        "`-q` passes `--quiet` to `git clone`: {}",
+        output.stderr
+    );
+
+    prole
+        .repo_state("my-repo-quiet")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn completions_all() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+
+    prole
+        .cmd()
+        .args(["completions", "--all", "--output", "completions"])
+        .status_checked()?;
+
+    for file in [
+        "git-prole.bash",
+        "git-prole.elv",
+        "git-prole.fish",
+        "_git-prole.ps1",
+        "_git-prole",
+    ] {
+        let path = prole.path(&format!("completions/{file}"));
+        assert!(path.exists(), "{path} should be generated by `--all`");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn completions_output() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+
+    prole
+        .cmd()
+        .args([
+            "completions",
+            "fish",
+            "--output",
+            "share/fish/git-prole.fish",
+        ])
+        .status_checked()?;
+
+    let completions = prole.contents("share/fish/git-prole.fish")?;
+    assert!(
+        completions.contains("complete -c git-prole"),
+        "Fish completions should be written to `--output`: {completions}"
+    );
+
+    Ok(())
+}
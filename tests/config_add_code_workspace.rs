@@ -0,0 +1,47 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_add_code_workspace() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        code_workspace = "my-repo.code-workspace"
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy"),
+        ])
+        .assert();
+
+    // The workspace file is created if it doesn't exist.
+    prole.assert_contents(&[(
+        "my-repo/my-repo.code-workspace",
+        expect![[r#"
+            {
+              "folders": [
+                {
+                  "path": "puppy"
+                }
+              ]
+            }
+        "#]],
+    )]);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_add_commands_fail_fast() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        commands_fail_fast = true
+        commands = [
+            "false",
+            "sh -c 'echo Puppy wuz here > puppy-log'",
+        ]
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["add", "puppy"])
+        .status_checked()
+        .unwrap_err();
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            // The second command is never run.
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .no_file("puppy-log"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_add_commands_fail_fast_default() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        commands = [
+            "false",
+            "sh -c 'echo Puppy wuz here > puppy-log'",
+        ]
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            // The failing command is logged and the second command is run anyways.
+            WorktreeState::new("puppy").branch("puppy").file(
+                "puppy-log",
+                expect![[r#"
+                    Puppy wuz here
+                "#]],
+            ),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_add_copy_ignored_exclude() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        copy_ignored_exclude = ["target"]
+        "#,
+    )?;
+
+    prole.sh("
+        cd my-repo/main || exit
+        printf '.env\n*.local.toml\ntarget/\n' >> .gitignore
+        git add .gitignore
+        git commit -m 'Add .gitignore'
+
+        echo 'PUPPY=doggy' > .env
+        echo 'puppy = true' > puppy.local.toml
+        mkdir target
+        echo 'puppy doggy' > target/compiled-animal-facts.txt
+        ")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main").status([
+                "!! .env",
+                "!! puppy.local.toml",
+                "!! target/",
+            ]),
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .upstream("main")
+                .file(
+                    ".env",
+                    expect![[r#"
+                        PUPPY=doggy
+                    "#]],
+                )
+                .file(
+                    "puppy.local.toml",
+                    expect![[r#"
+                        puppy = true
+                    "#]],
+                )
+                // The build directory isn't copied.
+                .no_file("target")
+                .status(["!! .env", "!! puppy.local.toml"]),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::setup_fake_program;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_add_direnv_allow() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+    let path = setup_fake_program(&prole, "direnv")?;
+
+    prole.sh("
+        cd my-repo/main || exit
+        echo 'use flake' > .envrc
+        git add .envrc
+        git commit -m 'Add .envrc'
+        ")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        direnv_allow = true
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .env("PATH", path)
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy"),
+        ])
+        .assert();
+
+    let root = prole.path("").canonicalize_utf8().unwrap();
+    let log = prole
+        .contents("direnv-log")?
+        .replace(root.as_str(), "$ROOT");
+    expect![[r#"
+        allow $ROOT/my-repo/puppy
+    "#]]
+    .assert_eq(&log);
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn config_add_hooks_dir() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        hooks_dir = ".githooks"
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--hooks-dir", "hooks", "doggy"])
+        .status_checked()?;
+
+    assert_eq!(
+        prole
+            .git("my-repo/puppy")
+            .config()
+            .get("core.hooksPath")?
+            .as_deref(),
+        Some(".githooks")
+    );
+    // `--hooks-dir` overrides the setting.
+    assert_eq!(
+        prole
+            .git("my-repo/doggy")
+            .config()
+            .get("core.hooksPath")?
+            .as_deref(),
+        Some("hooks")
+    );
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn config_add_hooks_dir_default() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    assert_eq!(
+        prole.git("my-repo/puppy").config().get("core.hooksPath")?,
+        None
+    );
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use command_error::CommandExt;
+use test_harness::setup_fake_program;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_add_issue_branch_template() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+    let path = setup_fake_program(&prole, "gh")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        issue_branch_template = "puppy/{n}"
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--issue", "42"])
+        .env("PATH", path)
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("42").branch("puppy/42").upstream("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_add_link_siblings() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        link_siblings = true
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            // The links are ignored, so they don't show up as untracked files.
+            WorktreeState::new("main")
+                .branch("main")
+                .status(["!! .worktrees/"]),
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .status(["!! .worktrees/"]),
+        ])
+        .assert();
+
+    assert_eq!(
+        prole
+            .path("my-repo/main/.worktrees/puppy")
+            .read_link_utf8()
+            .unwrap(),
+        "../../puppy"
+    );
+    assert_eq!(
+        prole
+            .path("my-repo/puppy/.worktrees/main")
+            .read_link_utf8()
+            .unwrap(),
+        "../../main"
+    );
+
+    Ok(())
+}
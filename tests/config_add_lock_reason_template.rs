@@ -0,0 +1,37 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use git_prole::LocalBranchRef;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn config_add_lock_reason_template() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        lock_reason_template = "{name} is {branch}"
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--lock", "--dir", "pup", "-b", "puppy/doggy"])
+        .status_checked()?;
+
+    assert_eq!(
+        prole
+            .git("my-repo")
+            .worktree()
+            .list()?
+            .for_branch(&LocalBranchRef::new("puppy/doggy".to_owned()))
+            .unwrap()
+            .locked
+            .as_deref(),
+        Some("pup is puppy/doggy")
+    );
+
+    Ok(())
+}
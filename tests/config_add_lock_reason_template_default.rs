@@ -0,0 +1,29 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use git_prole::LocalBranchRef;
+use test_harness::GitProle;
+
+#[test]
+fn config_add_lock_reason_template_default() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--lock", "puppy"])
+        .status_checked()?;
+
+    let worktrees = prole.git("my-repo").worktree().list()?;
+    let reason = worktrees
+        .for_branch(&LocalBranchRef::new("puppy".to_owned()))
+        .unwrap()
+        .locked
+        .as_deref()
+        .unwrap();
+    assert!(
+        reason.starts_with("created by git-prole on ") && reason.ends_with(" for puppy"),
+        "Unexpected lock reason: {reason:?}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_add_scratch_prefix() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        scratch_prefix = "scratch"
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--detach"])
+        .status_checked()?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--detach"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("scratch").detached("4023d080"),
+            WorktreeState::new("scratch-2").detached("4023d080"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_add_setup_tools() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    // Log the working directory as well as the arguments.
+    prole.sh(r#"
+        mkdir -p bin
+        printf '#!/bin/sh\necho "$PWD: $@" >> "$HOME/mise-log"\n' > bin/mise
+        chmod +x bin/mise
+
+        cd my-repo/main || exit
+        printf '[tools]\nnode = "22"\n' > mise.toml
+        git add mise.toml
+        git commit -m 'Add mise.toml'
+        "#)?;
+
+    prole.write_config(
+        r#"
+        [add]
+        setup_tools = true
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .env(
+            "PATH",
+            format!(
+                "{}:{}",
+                prole.path("bin"),
+                std::env::var("PATH").unwrap_or_default()
+            ),
+        )
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy"),
+        ])
+        .assert();
+
+    let root = prole.path("").canonicalize_utf8().unwrap();
+    let log = prole.contents("mise-log")?.replace(root.as_str(), "$ROOT");
+    expect![[r#"
+        $ROOT/my-repo/puppy: trust
+        $ROOT/my-repo/puppy: install
+    "#]]
+    .assert_eq(&log);
+
+    Ok(())
+}
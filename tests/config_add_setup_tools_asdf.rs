@@ -0,0 +1,60 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_add_setup_tools_asdf() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    // Log the working directory as well as the arguments.
+    prole.sh(r#"
+        mkdir -p bin
+        printf '#!/bin/sh\necho "$PWD: $@" >> "$HOME/asdf-log"\n' > bin/asdf
+        chmod +x bin/asdf
+
+        cd my-repo/main || exit
+        echo 'nodejs 22.0.0' > .tool-versions
+        git add .tool-versions
+        git commit -m 'Add .tool-versions'
+        "#)?;
+
+    prole.write_config(
+        r#"
+        [add]
+        setup_tools = true
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .env(
+            "PATH",
+            format!(
+                "{}:{}",
+                prole.path("bin"),
+                std::env::var("PATH").unwrap_or_default()
+            ),
+        )
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy"),
+        ])
+        .assert();
+
+    let root = prole.path("").canonicalize_utf8().unwrap();
+    let log = prole.contents("asdf-log")?.replace(root.as_str(), "$ROOT");
+    expect![[r#"
+        $ROOT/my-repo/puppy: install
+    "#]]
+    .assert_eq(&log);
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn config_add_share_submodules() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("dependency")?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        share_submodules = true
+        "#,
+    )?;
+
+    prole.sh(r#"
+        git config --global protocol.file.allow always
+        cd my-repo/main || exit
+        git submodule add "$PWD/../../dependency" dependency
+        git commit -m 'Add submodule'
+    "#)?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    // The submodule is checked out...
+    assert!(prole.path("my-repo/puppy/dependency/README.md").exists());
+    // ...and borrows objects from the submodule in `main`.
+    prole.sh(r#"
+        cd my-repo/puppy/dependency || exit
+        test -s "$(git rev-parse --git-path objects/info/alternates)"
+    "#)?;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn config_add_share_submodules_default() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("dependency")?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.sh(r#"
+        git config --global protocol.file.allow always
+        cd my-repo/main || exit
+        git submodule add "$PWD/../../dependency" dependency
+        git commit -m 'Add submodule'
+    "#)?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    // Submodules aren't initialized by default.
+    assert!(!prole.path("my-repo/puppy/dependency/README.md").exists());
+
+    Ok(())
+}
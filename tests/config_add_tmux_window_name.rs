@@ -0,0 +1,44 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::setup_fake_program;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_add_tmux_window_name() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+    let path = setup_fake_program(&prole, "tmux")?;
+
+    prole.write_config(
+        r#"
+        [add]
+        tmux_window_name = "my-repo:{branch}"
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["add", "--tmux", "-b", "doggy/puppy"])
+        .env("PATH", path)
+        .env("TMUX", "/tmp/tmux-1000/default,1234,0")
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("doggy/puppy"),
+        ])
+        .assert();
+
+    let root = prole.path("").canonicalize_utf8().unwrap();
+    let log = prole.contents("tmux-log")?.replace(root.as_str(), "$ROOT");
+    expect![[r#"
+        new-window -n my-repo:doggy/puppy -c $ROOT/my-repo/puppy
+    "#]]
+    .assert_eq(&log);
+
+    Ok(())
+}
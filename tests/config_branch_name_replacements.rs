@@ -0,0 +1,51 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_branch_name_replacements() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+    prole.write_config(
+        r#"
+        [[add.branch_name_replacements]]
+        find = '''(\w+/\w{1,4}-\d{1,5})-.*'''
+        replace = '''$1'''
+
+        [[add.branch_replacements]]
+        find = '''\w+/(\w{1,4}-\d{1,5})'''
+        replace = '''$1'''
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "-b", "doggy/pup-1234-my-cool-feature"])
+        .status_checked()
+        .unwrap();
+
+    // `--force-branch` creates a branch too.
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "-B", "doggy/pup-5678-another-feature"])
+        .status_checked()
+        .unwrap();
+
+    // The branch name is rewritten first, and then `add.branch_replacements` determines the
+    // directory name from the rewritten branch name.
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("pup-1234")
+                .branch("doggy/pup-1234")
+                .upstream("main"),
+            WorktreeState::new("pup-5678")
+                .branch("doggy/pup-5678")
+                .upstream("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_branch_name_replacements_default() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+    prole.write_config(
+        r#"
+        [[add.branch_replacements]]
+        find = '''\w+/\w{1,4}-\d{1,5}-(\w+).*'''
+        replace = '''$1'''
+        "#,
+    )?;
+
+    // Without `add.branch_name_replacements`, only the directory name is changed.
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "-b", "doggy/pup-1234-puppy-feature"])
+        .status_checked()
+        .unwrap();
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy")
+                .branch("doggy/pup-1234-puppy-feature")
+                .upstream("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
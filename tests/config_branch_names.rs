@@ -30,7 +30,10 @@ fn config_branch_names() -> miette::Result<()> {
         "#,
     )?;
 
-    prole.cd_cmd("my-repo").arg("convert").status_checked()?;
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
 
     prole
         .repo_state("my-repo")
@@ -21,7 +21,10 @@ fn config_branch_names_default() -> miette::Result<()> {
         git remote rename origin puppy
         ")?;
 
-    prole.cd_cmd("my-repo").arg("convert").status_checked()?;
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
 
     prole
         .repo_state("my-repo")
@@ -0,0 +1,32 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn config_clone_default_remote() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("remote/my-repo")?;
+
+    prole.write_config(
+        r#"
+        [clone]
+        default_remote = "origin"
+        "#,
+    )?;
+
+    prole
+        .cmd()
+        .args(["clone", "remote/my-repo"])
+        .status_checked()?;
+
+    assert_eq!(
+        prole
+            .git("my-repo/main")
+            .config()
+            .get("checkout.defaultRemote")?,
+        Some("origin".to_owned())
+    );
+
+    Ok(())
+}
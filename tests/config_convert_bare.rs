@@ -0,0 +1,28 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_convert_bare() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.write_config(
+        "
+        [convert]
+        bare = false
+        ",
+    )?;
+    prole.setup_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo/main")
+        .git_dir(".git")
+        .worktrees([WorktreeState::new("").is_main(true).branch("main")])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use camino::Utf8Path;
+use command_error::CommandExt;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn config_convert_commands() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [convert]
+        commands = [
+            { sh = '''
+                pwd -P > "$HOME/convert-log"
+                echo "$GIT_PROLE_CONTAINER" >> "$HOME/convert-log"
+                echo "$GIT_PROLE_DEFAULT_WORKTREE" >> "$HOME/convert-log"
+            ''' },
+        ]
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
+
+    let log = prole
+        .contents("convert-log")?
+        .lines()
+        .map(|path| Utf8Path::new(path).canonicalize_utf8().unwrap())
+        .collect::<Vec<_>>();
+
+    let container = prole.path("my-repo").canonicalize_utf8().unwrap();
+    assert_eq!(
+        log,
+        [container.clone(), container.clone(), container.join("main")]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn config_convert_commands_dry_run() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [convert]
+        commands = [
+            "touch converted",
+        ]
+        "#,
+    )?;
+
+    let stderr = prole
+        .cd_cmd("my-repo")
+        .args(["--dry-run", "convert"])
+        .output_checked_utf8()?
+        .stderr;
+
+    assert!(
+        stderr.contains("touch converted"),
+        "The command is printed: {stderr}"
+    );
+    assert!(!prole.path("my-repo/converted").exists());
+
+    Ok(())
+}
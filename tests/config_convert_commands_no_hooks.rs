@@ -0,0 +1,27 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn config_convert_commands_no_hooks() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [convert]
+        commands = [
+            "touch converted",
+        ]
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes", "--no-hooks"])
+        .status_checked()?;
+
+    assert!(prole.path("my-repo/main").exists());
+    assert!(!prole.path("my-repo/converted").exists());
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_convert_git_dir_name() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        [convert]
+        git_dir_name = "my-repo.git"
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo/main")
+        .git_dir("../my-repo.git")
+        .worktrees([
+            WorktreeState::new("../my-repo.git").bare(),
+            WorktreeState::new(".").branch("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
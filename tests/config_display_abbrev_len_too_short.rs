@@ -0,0 +1,30 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn config_display_abbrev_len_too_short() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+
+    prole.sh("
+        cd my-repo
+        git switch --detach
+        ")?;
+
+    prole.write_config(
+        r#"
+        [display]
+        abbrev_len = 2
+        "#,
+    )?;
+
+    let stderr = prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .output_checked_utf8()?
+        .stderr;
+
+    assert!(stderr.contains("~/my-repo 4023 "), "{stderr}");
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+
+#[test]
+fn config_init_merge() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.write_config(
+        "\
+        remote_names = [\"puppy\"]\n\
+        \n\
+        [add]\n\
+        copy_ignored = false\n\
+        ",
+    )?;
+
+    prole
+        .cmd()
+        .args(["config", "init", "--merge"])
+        .status_checked()
+        .unwrap();
+
+    prole.assert_contents(&[(
+        ".config/git-prole/config.toml",
+        expect![[r#"
+            remote_names = ["puppy"]
+
+            [add]
+            copy_ignored = false
+
+            # Settings added by `git prole config init --merge`, with their default values.
+            # To use them, uncomment them and move them into the right section above.
+            # branch_names = ["main", "master", "trunk"]
+            # repo_root_markers = []
+            # safe_roots = []
+            # worktree_parent_depth = 1
+            #
+            # [add]
+            # branch_name_replacements = []
+            # branch_replacements = []
+            # commands = []
+            # commands_fail_fast = false
+            # copy_ignored_exclude = []
+            # copy_ignored_include = []
+            # copy_ignored_symlinks = true
+            # direnv_allow = false
+            # inherit_remote = false
+            # issue_branch_template = "issue-{n}"
+            # link_siblings = false
+            # lock_reason_template = "created by git-prole on {date} for {branch}"
+            # scratch_prefix = "work"
+            # setup_tools = false
+            # share_submodules = false
+            # tmux_window_name = "{name}"
+            #
+            # [clone]
+            # enable_gh = false
+            #
+            # [convert]
+            # bare = true
+            # commands = []
+            # git_dir_name = ".git"
+            #
+            # [display]
+            # abbrev_len = 8
+            #
+            # [remote_branch_names]
+        "#]],
+    )]);
+
+    // Merging again is a no-op.
+    let contents = prole.contents(".config/git-prole/config.toml")?;
+    prole
+        .cmd()
+        .args(["config", "init", "--merge"])
+        .status_checked()
+        .unwrap();
+    assert_eq!(prole.contents(".config/git-prole/config.toml")?, contents);
+
+    Ok(())
+}
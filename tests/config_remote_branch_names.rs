@@ -0,0 +1,68 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_remote_branch_names() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("remote/my-repo")?;
+    prole.sh("
+        cd remote/my-repo || exit
+        git branch master
+        ")?;
+    prole
+        .cmd()
+        .args(["clone", "remote/my-repo"])
+        .status_checked()?;
+    prole.sh("
+        cd my-repo/main || exit
+        git remote add upstream ../../remote/my-repo
+        git fetch upstream
+        ")?;
+
+    prole.write_config(
+        r#"
+        [remote_branch_names]
+        origin = ["main"]
+        upstream = ["trunk", "master"]
+        "#,
+    )?;
+
+    // `upstream` is preferred over `origin`, and its default branch is `master` rather than the
+    // remote's `HEAD`.
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole.write_config(
+        r#"
+        remote_names = ["origin"]
+
+        [remote_branch_names]
+        origin = ["master"]
+        upstream = ["main"]
+        "#,
+    )?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "doggy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .upstream("upstream/master"),
+            WorktreeState::new("doggy")
+                .branch("doggy")
+                .upstream("origin/master"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -18,7 +18,10 @@ fn config_remote_names() -> miette::Result<()> {
         "#,
     )?;
 
-    prole.cd_cmd("my-repo").arg("convert").status_checked()?;
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
 
     prole
         .repo_state("my-repo")
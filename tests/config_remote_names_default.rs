@@ -18,7 +18,10 @@ fn config_remote_names_default() -> miette::Result<()> {
     //
     // The default config says `upstream` is more important than `origin`, so we use that!
 
-    prole.cd_cmd("my-repo").arg("convert").status_checked()?;
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
 
     prole
         .repo_state("my-repo")
@@ -0,0 +1,40 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_repo_root_markers() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo/main/repo")?;
+
+    prole.write_config(
+        r#"
+        repo_root_markers = ["WORKSPACE"]
+        "#,
+    )?;
+
+    prole.sh("
+        # A marker in the main worktree itself doesn't count.
+        cd my-repo/main/repo || exit
+        touch WORKSPACE
+        git add WORKSPACE
+        git commit -m 'Add WORKSPACE'
+
+        touch ../../WORKSPACE
+        ")?;
+
+    prole
+        .cd_cmd("my-repo/main/repo")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo/main/repo")
+        .worktrees([
+            WorktreeState::new("").is_main(true).branch("main"),
+            WorktreeState::new("../../puppy").branch("puppy"),
+        ])
+        .assert();
+
+    Ok(())
+}
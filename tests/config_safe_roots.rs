@@ -0,0 +1,62 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_safe_roots() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.write_config(
+        r#"
+        safe_roots = ["~/code"]
+        "#,
+    )?;
+    prole.setup_repo("my-repo")?;
+    prole.setup_repo("code/my-repo")?;
+
+    let error = prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("Refusing to operate on"),
+        "`convert` should refuse to convert repositories outside of the `safe_roots`: {error}"
+    );
+
+    // Nothing was changed.
+    prole
+        .repo_state("my-repo")
+        .git_dir(".git")
+        .worktrees([WorktreeState::new("").branch("main")])
+        .assert();
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes", "--allow-outside"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+        ])
+        .assert();
+
+    // Repositories inside the `safe_roots` are converted as usual.
+    prole
+        .cd_cmd("code/my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
+
+    prole
+        .repo_state("code/my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
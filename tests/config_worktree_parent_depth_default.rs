@@ -0,0 +1,24 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn config_worktree_parent_depth_default() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo/main/repo")?;
+
+    prole
+        .cd_cmd("my-repo/main/repo")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo/main/repo")
+        .worktrees([
+            WorktreeState::new("").is_main(true).branch("main"),
+            WorktreeState::new("../puppy").branch("puppy"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -22,7 +22,7 @@ fn convert_bare_dot_git() -> miette::Result<()> {
 
     prole
         .cd_cmd("my-repo/main")
-        .arg("convert")
+        .args(["convert", "--yes"])
         .status_checked()?;
 
     prole
@@ -20,7 +20,10 @@ fn convert_bare_dot_git_from_parent() -> miette::Result<()> {
         git worktree add --detach ../doggy
         "#)?;
 
-    prole.cd_cmd("my-repo").arg("convert").status_checked()?;
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
 
     prole
         .repo_state("my-repo")
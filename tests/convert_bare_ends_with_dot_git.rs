@@ -22,7 +22,7 @@ fn convert_bare_ends_with_dot_git() -> miette::Result<()> {
 
     prole
         .cd_cmd("my-repo.git")
-        .arg("convert")
+        .args(["convert", "--yes"])
         .status_checked()?;
 
     prole
@@ -20,7 +20,10 @@ fn convert_bare_no_dot() -> miette::Result<()> {
         git worktree add --detach ../doggy
         "#)?;
 
-    prole.cd_cmd("main").arg("convert").status_checked()?;
+    prole
+        .cd_cmd("main")
+        .args(["convert", "--yes"])
+        .status_checked()?;
 
     prole
         .repo_state("my-repo")
@@ -0,0 +1,36 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_bare_scattered_worktrees() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("remote/my-repo")?;
+    prole.sh(r#"
+        git clone --bare remote/my-repo my-repo/.git
+        cd my-repo/.git || exit
+        git worktree add ../../elsewhere/main main
+        git worktree add -b puppy ../../scattered/deep/puppy-worktree
+        "#)?;
+
+    prole
+        .cd_cmd("my-repo/.git")
+        .args(["convert", "--yes"])
+        .status_checked()?;
+
+    // The worktrees are moved into the container and renamed after their branches, and the bare
+    // repository is left where it is.
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy"),
+        ])
+        .assert();
+
+    assert!(!prole.path("elsewhere/main").exists());
+    assert!(!prole.path("scattered/deep/puppy-worktree").exists());
+
+    Ok(())
+}
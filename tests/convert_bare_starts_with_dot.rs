@@ -22,7 +22,7 @@ fn convert_bare_starts_with_dot() -> miette::Result<()> {
 
     prole
         .cd_cmd("my-repo/main")
-        .arg("convert")
+        .args(["convert", "--yes"])
         .status_checked()?;
 
     prole
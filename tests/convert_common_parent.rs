@@ -15,7 +15,7 @@ fn convert_common_parent() -> miette::Result<()> {
 
     prole
         .cd_cmd("my-prefix/my-repo")
-        .arg("convert")
+        .args(["convert", "--yes"])
         .status_checked()?;
 
     prole
@@ -19,7 +19,7 @@ fn convert_common_parent_extra_files() -> miette::Result<()> {
 
     prole
         .cd_cmd("my-prefix/my-repo")
-        .arg("convert")
+        .args(["convert", "--yes"])
         .status_checked()?;
 
     prole
@@ -17,7 +17,7 @@ fn convert_common_prefix() -> miette::Result<()> {
 
     prole
         .cd_cmd("my-prefix/my-repo")
-        .arg("convert")
+        .args(["convert", "--yes"])
         .status_checked()?;
 
     prole
@@ -0,0 +1,54 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use git_prole::GitLike;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_container() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+    prole.sh(r#"
+        cd my-repo || exit
+        git switch -c puppy
+        "#)?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args([
+            "convert",
+            "--yes",
+            "--git-dir-name",
+            "my-repo.git",
+            "--container",
+            "../work/my-repo",
+            "../bare",
+        ])
+        .status_checked()?;
+
+    assert!(!prole.path("my-repo").exists());
+    assert!(prole.path("bare/my-repo.git").is_dir());
+
+    prole
+        .repo_state("work/my-repo/main")
+        .git_dir("../../../bare/my-repo.git")
+        .worktrees([
+            WorktreeState::new("../../../bare/my-repo.git").bare(),
+            WorktreeState::new(".").branch("main").file(
+                "README.md",
+                expect![[r#"
+                    puppy doggy
+                "#]],
+            ),
+            WorktreeState::new("../puppy").branch("puppy").status([]),
+        ])
+        .assert();
+
+    // The worktrees resolve to the repository.
+    assert_eq!(
+        prole.git("work/my-repo/puppy").path().git_common_dir()?,
+        prole.path("bare/my-repo.git")
+    );
+
+    Ok(())
+}
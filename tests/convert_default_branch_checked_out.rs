@@ -8,7 +8,10 @@ fn convert_default_branch_checked_out() -> miette::Result<()> {
     let prole = GitProle::new()?;
     prole.setup_repo("my-repo")?;
 
-    prole.cd_cmd("my-repo").arg("convert").status_checked()?;
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
 
     prole
         .repo_state("my-repo")
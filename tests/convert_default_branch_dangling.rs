@@ -0,0 +1,33 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_default_branch_dangling() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("remote/my-repo")?;
+
+    prole.sh(r#"
+        git clone remote/my-repo my-repo
+        cd my-repo || exit
+        git switch -c puppy
+        git branch -D main
+        # The remote's default branch points nowhere.
+        git symbolic-ref refs/remotes/origin/HEAD refs/remotes/origin/doggy
+        "#)?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("puppy").branch("puppy"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_default_worktree_name_from_dir_new() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+
+    prole.sh("
+        cd my-repo
+        git switch -c puppy
+        echo 'softie cutie' > README.md
+        git commit -am 'cooler readme'
+        ")?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes", "--default-worktree-name-from-dir"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            // The new worktree for the default branch is named after the directory.
+            WorktreeState::new("my-repo").branch("main").file(
+                "README.md",
+                expect![[r#"
+                    puppy doggy
+                "#]],
+            ),
+            WorktreeState::new("puppy").branch("puppy").file(
+                "README.md",
+                expect![[r#"
+                    softie cutie
+                "#]],
+            ),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -9,7 +9,7 @@ fn convert_destination_explicit() -> miette::Result<()> {
 
     prole
         .cd_cmd("my-repo")
-        .args(["convert", "../puppy"])
+        .args(["convert", "--yes", "../puppy"])
         .status_checked()?;
 
     prole.sh("ls -la && ls -la puppy")?;
@@ -16,7 +16,10 @@ fn convert_detached_head() -> miette::Result<()> {
         git switch --detach
         ")?;
 
-    prole.cd_cmd("my-repo").arg("convert").status_checked()?;
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
 
     assert_eq!(
         prole.git("my-repo/main").refs().head_kind()?,
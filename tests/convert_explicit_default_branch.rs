@@ -15,7 +15,7 @@ fn convert_explicit_default_branch() -> miette::Result<()> {
 
     prole
         .cd_cmd("my-repo")
-        .args(["convert", "--default-branch", "a/a"])
+        .args(["convert", "--yes", "--default-branch", "a/a"])
         .status_checked()?;
 
     prole
@@ -9,7 +9,7 @@ fn convert_explicit_default_branch_not_found() -> miette::Result<()> {
 
     prole
         .cd_cmd("my-repo")
-        .args(["convert", "--default-branch", "d/a"])
+        .args(["convert", "--yes", "--default-branch", "d/a"])
         .status_checked()
         .unwrap_err();
 
@@ -0,0 +1,35 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_fetch_fails() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("remote/my-repo")?;
+
+    prole.sh(r#"
+        git clone remote/my-repo my-repo
+        cd my-repo || exit
+        git switch -c puppy
+        git branch -D main
+        # `origin/main` exists, but fetching it fails.
+        git remote set-url origin ../remote/nonexistent
+        "#)?;
+
+    let error = prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("nonexistent"));
+
+    // The repository is left alone.
+    prole
+        .repo_state("my-repo")
+        .git_dir(".git")
+        .worktrees([WorktreeState::new("").is_main(true).branch("puppy")])
+        .assert();
+
+    Ok(())
+}
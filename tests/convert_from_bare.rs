@@ -22,7 +22,7 @@ fn convert_from_bare() -> miette::Result<()> {
 
     prole
         .cd_cmd("my-repo/.git")
-        .arg("convert")
+        .args(["convert", "--yes"])
         .status_checked()?;
 
     prole
@@ -0,0 +1,56 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_git_dir_name() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+    prole.sh(r#"
+        cd my-repo || exit
+        git switch -c puppy
+        "#)?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes", "--git-dir-name", "bare"])
+        .status_checked()?;
+
+    assert!(!prole.path("my-repo/.git").exists());
+
+    // Git doesn't find `my-repo/bare` from `my-repo`, so we check from a worktree.
+    prole
+        .repo_state("my-repo/main")
+        .git_dir("../bare")
+        .worktrees([
+            WorktreeState::new("../bare").bare(),
+            WorktreeState::new(".").branch("main").file(
+                "README.md",
+                expect![[r#"
+                    puppy doggy
+                "#]],
+            ),
+            WorktreeState::new("../puppy").branch("puppy"),
+        ])
+        .assert();
+
+    // Subsequent commands find the repository.
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "silly"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo/silly")
+        .git_dir("../bare")
+        .worktrees([
+            WorktreeState::new("../bare").bare(),
+            WorktreeState::new("../main").branch("main"),
+            WorktreeState::new("../puppy").branch("puppy"),
+            WorktreeState::new(".").branch("silly"),
+        ])
+        .assert();
+
+    Ok(())
+}
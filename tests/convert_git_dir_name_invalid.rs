@@ -0,0 +1,29 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_git_dir_name_invalid() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+
+    let error = prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes", "--git-dir-name", "puppy/bare"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("Git directory name must be a single path component"),
+        "`--git-dir-name` should reject paths: {error}"
+    );
+
+    // Nothing was changed.
+    prole
+        .repo_state("my-repo")
+        .git_dir(".git")
+        .worktrees([WorktreeState::new("").branch("main")])
+        .assert();
+
+    Ok(())
+}
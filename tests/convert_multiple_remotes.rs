@@ -8,7 +8,10 @@ fn convert_multiple_remotes() -> miette::Result<()> {
     let prole = GitProle::new()?;
     setup_repo_multiple_remotes(&prole, "my-remotes/my-repo", "my-repo")?;
 
-    prole.cd_cmd("my-repo").arg("convert").status_checked()?;
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
 
     prole
         .repo_state("my-repo")
@@ -15,7 +15,10 @@ fn convert_multiple_worktrees() -> miette::Result<()> {
         git worktree add ../doggy
         ")?;
 
-    prole.cd_cmd("my-repo").arg("convert").status_checked()?;
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
 
     prole
         .repo_state("my-repo")
@@ -0,0 +1,48 @@
+use command_error::CommandExt;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_no_bare() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+
+    prole.sh("
+        # Another path here keeps `git-prole` from using the tempdir as the root.
+        mkdir my-other-repo
+        cd my-repo || exit
+        git worktree add ../puppy
+        ")?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes", "--no-bare"])
+        .status_checked()?;
+
+    // The main worktree keeps the Git directory.
+    prole
+        .repo_state("my-repo/main")
+        .git_dir(".git")
+        .worktrees([
+            WorktreeState::new("").is_main(true).branch("main"),
+            WorktreeState::new("../puppy").branch("puppy"),
+        ])
+        .assert();
+
+    // The worktrees are already repaired, so there's nothing left to do.
+    let repair = prole
+        .git("my-repo/main")
+        .command()
+        .args(["worktree", "repair"])
+        .output_checked_utf8()?;
+    assert_eq!(repair.stdout, "");
+    assert_eq!(repair.stderr, "");
+    prole
+        .git("my-repo/puppy")
+        .command()
+        .arg("status")
+        .status_checked()?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_no_bare_unborn_head() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("remote/my-repo")?;
+    prole.sh(r#"
+        mkdir my-repo
+        cd my-repo || exit
+        git init
+        git remote add origin ../remote/my-repo
+        git fetch
+        "#)?;
+
+    // The unborn main worktree would have to keep the Git directory, so we can't replace it with
+    // a worktree for the default branch.
+    let error = prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes", "--no-bare"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("has no commits yet"),
+        "`convert --no-bare` should reject an unborn main worktree: {error}"
+    );
+
+    // The repository is left alone.
+    prole
+        .repo_state("my-repo")
+        .git_dir(".git")
+        .worktrees([WorktreeState::new("").is_main(true).branch("main")])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_no_fetch() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("remote/my-repo")?;
+
+    prole.sh(r#"
+        git clone remote/my-repo my-repo
+        cd my-repo || exit
+        git switch -c puppy
+        git branch -D main
+        # We're offline now!
+        rm -rf ../remote
+    "#)?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes", "--no-fetch"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main")
+                .branch("main")
+                .upstream("origin/main"),
+            WorktreeState::new("puppy").branch("puppy"),
+        ])
+        .assert();
+
+    Ok(())
+}
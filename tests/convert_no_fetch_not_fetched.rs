@@ -0,0 +1,36 @@
+use command_error::CommandExt;
+use test_harness::setup_repo_multiple_remotes;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_no_fetch_not_fetched() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    setup_repo_multiple_remotes(&prole, "my-remotes/my-repo", "my-repo")?;
+
+    prole.sh(r#"
+        cd my-repo || exit
+        git config checkout.defaultRemote a
+        # We know the default branch of `a`, but we haven't fetched it.
+        git symbolic-ref refs/remotes/a/HEAD refs/remotes/a/a
+        # We're offline now!
+        rm -rf ../my-remotes
+    "#)?;
+
+    let error = prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes", "--no-fetch"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("only exists on the remote"), "{error}");
+
+    // The repository is left alone.
+    prole
+        .repo_state("my-repo")
+        .git_dir(".git")
+        .worktrees([WorktreeState::new("").is_main(true).branch("main")])
+        .assert();
+
+    Ok(())
+}
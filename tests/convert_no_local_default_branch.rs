@@ -14,7 +14,10 @@ fn convert_no_local_default_branch() -> miette::Result<()> {
         git branch -D main
     "#)?;
 
-    prole.cd_cmd("my-repo").arg("convert").status_checked()?;
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
 
     prole
         .repo_state("my-repo")
@@ -25,7 +25,7 @@ fn convert_no_op() -> miette::Result<()> {
 
     let output = prole
         .cd_cmd("my-repo")
-        .arg("convert")
+        .args(["convert", "--yes"])
         .output_checked_utf8()?;
 
     assert!(
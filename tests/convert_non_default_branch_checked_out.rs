@@ -15,7 +15,10 @@ fn convert_non_default_branch_checked_out() -> miette::Result<()> {
         git commit -am 'cooler readme'
         ")?;
 
-    prole.cd_cmd("my-repo").arg("convert").status_checked()?;
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
 
     prole
         .repo_state("my-repo")
@@ -0,0 +1,30 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_not_a_tty() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+
+    // stdin isn't a terminal, so we can't ask for confirmation.
+    let error = prole
+        .cd_cmd("my-repo")
+        .arg("convert")
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("stdin isn't a terminal"),
+        "`convert` should require `--yes` when stdin isn't a terminal: {error}"
+    );
+
+    // Nothing was changed.
+    prole
+        .repo_state("my-repo")
+        .git_dir(".git")
+        .worktrees([WorktreeState::new("").branch("main")])
+        .assert();
+
+    Ok(())
+}
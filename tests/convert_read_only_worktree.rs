@@ -0,0 +1,57 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_read_only_worktree() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+
+    prole.sh("
+        mkdir read-only
+        cd my-repo || exit
+        git worktree add ../read-only/puppy
+        chmod a-w ../read-only
+        ")?;
+
+    let result = prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .output_checked_utf8();
+
+    // Let the tempdir get cleaned up.
+    prole.sh("chmod u+w read-only")?;
+
+    match result {
+        Ok(_) => {
+            // Permissions aren't enforced for some users (e.g. `root`), so the worktree can be
+            // moved anyways.
+            prole
+                .repo_state("my-repo")
+                .worktrees([
+                    WorktreeState::new_bare(),
+                    WorktreeState::new("main").branch("main"),
+                    WorktreeState::new("puppy").branch("puppy"),
+                ])
+                .assert();
+        }
+        Err(error) => {
+            let error = error.to_string();
+            assert!(
+                error.contains("read-only/puppy can't be moved")
+                    && error.contains("read-only isn't writable"),
+                "`convert` should name the worktree that can't be moved: {error}"
+            );
+            // Nothing was moved.
+            prole
+                .repo_state("my-repo")
+                .worktrees([
+                    WorktreeState::new("").is_main(true).branch("main"),
+                    WorktreeState::new("../read-only/puppy").branch("puppy"),
+                ])
+                .assert();
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_skip_default_worktree() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+
+    prole.sh("
+        cd my-repo
+        git switch -c puppy
+        echo 'softie cutie' > README.md
+        git commit -am 'cooler readme'
+        ")?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes", "--skip-default-worktree"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("puppy").branch("puppy").file(
+                "README.md",
+                expect![[r#"
+                    softie cutie
+                "#]],
+            ),
+        ])
+        .assert();
+
+    Ok(())
+}
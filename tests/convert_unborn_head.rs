@@ -0,0 +1,29 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_unborn_head() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.sh(r#"
+        mkdir my-repo
+        cd my-repo || exit
+        git init
+        echo "puppy doggy" > README.md
+        "#)?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()
+        .unwrap_err();
+
+    // The repository is left alone.
+    prole
+        .repo_state("my-repo")
+        .git_dir(".git")
+        .worktrees([WorktreeState::new("").is_main(true).branch("main")])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_unborn_head_remote() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("remote/my-repo")?;
+    prole.sh(r#"
+        mkdir my-repo
+        cd my-repo || exit
+        git init
+        git remote add origin ../remote/my-repo
+        git fetch --depth 1 origin
+        git remote set-head origin --auto
+        "#)?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main")
+                .branch("main")
+                .upstream("origin/main")
+                .file(
+                    "README.md",
+                    expect![[r#"
+                        puppy doggy
+                    "#]],
+                )
+                .status([]),
+        ])
+        .assert();
+
+    Ok(())
+}
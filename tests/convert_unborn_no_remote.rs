@@ -0,0 +1,30 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn convert_unborn_no_remote() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.sh(r#"
+        mkdir my-repo
+        cd my-repo || exit
+        git init
+        "#)?;
+
+    let error = prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("has no commits yet"));
+
+    // The repository is left alone.
+    prole
+        .repo_state("my-repo")
+        .git_dir(".git")
+        .worktrees([WorktreeState::new("").is_main(true).branch("main")])
+        .assert();
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use command_error::CommandExt;
 use expect_test::expect;
+use pretty_assertions::assert_eq;
 use test_harness::GitProle;
 use test_harness::WorktreeState;
 
@@ -22,7 +23,16 @@ fn convert_uncommitted_changes() -> miette::Result<()> {
             .status(["M  README.md"])])
         .assert();
 
-    prole.cd_cmd("my-repo").arg("convert").status_checked()?;
+    let stderr = prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .output_checked_utf8()?
+        .stderr;
+
+    assert!(
+        stderr.contains("I'll stash the changes in ~/my-repo and restore them afterwards"),
+        "The plan mentions the stash: {stderr}"
+    );
 
     prole
         .repo_state("my-repo")
@@ -47,16 +57,19 @@ fn convert_uncommitted_changes() -> miette::Result<()> {
                         softie cutie
                     "#]],
                 )
-                .status([
-                    // /!\ /!\ /!\ /!\ /!\ /!\
-                    // TODO: This is a bug!!
-                    // We run a `git reset`, so we lose the staged changes!
-                    // Fix: Bring back the `git stash` if anything is staged?
-                    // /!\ /!\ /!\ /!\ /!\ /!\
-                    " M README.md",
-                ]),
+                // Staged changes are still staged.
+                .status(["M  README.md"]),
         ])
         .assert();
 
+    // The stash is popped.
+    let stashes = prole
+        .git("my-repo/puppy")
+        .command()
+        .args(["stash", "list"])
+        .output_checked_utf8()?
+        .stdout;
+    assert_eq!(stashes, "");
+
     Ok(())
 }
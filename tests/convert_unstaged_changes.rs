@@ -21,7 +21,10 @@ fn convert_unstaged_changes() -> miette::Result<()> {
             .status([" M README.md"])])
         .assert();
 
-    prole.cd_cmd("my-repo").arg("convert").status_checked()?;
+    prole
+        .cd_cmd("my-repo")
+        .args(["convert", "--yes"])
+        .status_checked()?;
 
     prole
         .repo_state("my-repo")
@@ -0,0 +1,32 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn directory() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    // Like `git -C`, run as if we were in `my-repo/main`.
+    prole
+        .cmd()
+        .args(["-C", "my-repo/main", "add", "puppy"])
+        .status_checked()?;
+
+    prole
+        .cmd()
+        .args(["--directory", "my-repo/puppy", "add", "doggy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy"),
+            WorktreeState::new("doggy").branch("doggy"),
+        ])
+        .assert();
+
+    Ok(())
+}
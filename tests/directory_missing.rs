@@ -0,0 +1,21 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn directory_missing() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    let error = prole
+        .cmd()
+        .args(["-C", "my-repo/puppy", "add", "doggy"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("`--directory` is not a directory"),
+        "`-C` should fail for a missing directory: {error}"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn directory_relative_path() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    // Like `git -C`, relative paths are resolved from the `-C` directory, not the directory
+    // we're actually in.
+    prole
+        .cmd()
+        .args(["-C", "my-repo/main", "add", "../puppy"])
+        .status_checked()?;
+
+    assert!(!prole.path("puppy").exists());
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy"),
+        ])
+        .assert();
+
+    Ok(())
+}
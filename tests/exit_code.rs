@@ -0,0 +1,48 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn exit_code() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.sh("mkdir puppy")?;
+    prole.setup_worktree_repo("my-repo")?;
+    // Don't find any repositories the temporary directory is in.
+    let ceiling = prole.path("").canonicalize_utf8().unwrap();
+
+    let exit_code = |dir: &str, args: &[&str]| {
+        prole
+            .cd_cmd(dir)
+            .args(args)
+            .env("GIT_CEILING_DIRECTORIES", ceiling.as_str())
+            .output_checked_with_utf8::<String>(|_output| Ok(()))
+            .unwrap()
+            .status
+            .code()
+    };
+
+    assert_eq!(exit_code("my-repo/main", &["whereami"]), Some(0));
+    // Usage error.
+    assert_eq!(exit_code("my-repo/main", &["add", "--puppy"]), Some(2));
+    // Not a repository.
+    assert_eq!(exit_code("puppy", &["whereami"]), Some(3));
+    // Dirty worktree.
+    prole.write_config(
+        r#"
+        [add]
+        commands = [
+            "sh -c 'echo puppy >> README.md'",
+        ]
+        "#,
+    )?;
+    assert_eq!(
+        exit_code("my-repo/main", &["add", "--require-clean", "doggy"]),
+        Some(4)
+    );
+    // Other errors.
+    assert_eq!(
+        exit_code("my-repo/main", &["add", "--require-clean", "doggy"]),
+        Some(1)
+    );
+
+    Ok(())
+}
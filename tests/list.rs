@@ -0,0 +1,39 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+
+#[test]
+fn list() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--detach", "doggy"])
+        .status_checked()?;
+
+    prole.sh("
+        cd my-repo/puppy || exit
+        echo 'puppy doggy!' > README.md
+        ")?;
+
+    let stdout = prole
+        .cd_cmd("my-repo/main")
+        .arg("list")
+        .output_checked_utf8()?
+        .stdout;
+
+    expect![[r#"
+        ~/my-repo/.git   bare            [main]
+        ~/my-repo/doggy  4023d080
+        ~/my-repo/main   main
+        ~/my-repo/puppy  puppy     main  *
+    "#]]
+    .assert_eq(&stdout);
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use command_error::CommandExt;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn list_absolute() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    let stdout = prole
+        .cd_cmd("my-repo/main")
+        .args(["list", "--absolute"])
+        .output_checked_utf8()?
+        .stdout;
+
+    let paths = stdout
+        .lines()
+        .map(|line| line.split_once("  ").unwrap().0.trim_end())
+        .collect::<Vec<_>>();
+
+    let expected = [".git", "main", "puppy"].map(|name| {
+        prole
+            .path(&format!("my-repo/{name}"))
+            .canonicalize_utf8()
+            .unwrap()
+    });
+
+    assert_eq!(paths, expected.each_ref().map(|path| path.as_str()));
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+
+#[test]
+fn list_from_container_git_dir_name() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.write_config(
+        r#"
+        [convert]
+        git_dir_name = ".bare"
+        "#,
+    )?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    // Don't find any repositories the temporary directory is in.
+    let ceiling = prole.path("").canonicalize_utf8().unwrap();
+
+    // Git doesn't find `.bare` on its own.
+    for dir in ["my-repo", "my-repo/.bare"] {
+        let stdout = prole
+            .cd_cmd(dir)
+            .arg("list")
+            .env("GIT_CEILING_DIRECTORIES", ceiling.as_str())
+            .output_checked_utf8()?
+            .stdout;
+
+        expect![[r#"
+            ~/my-repo/.bare  bare  [main]
+            ~/my-repo/main   main
+        "#]]
+        .assert_eq(&stdout);
+    }
+
+    Ok(())
+}
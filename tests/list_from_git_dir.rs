@@ -0,0 +1,21 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+
+#[test]
+fn list_from_git_dir() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    for dir in ["my-repo/.git", "my-repo/.git/refs"] {
+        let stdout = prole.cd_cmd(dir).arg("list").output_checked_utf8()?.stdout;
+
+        expect![[r#"
+            ~/my-repo/.git  bare  [main]
+            ~/my-repo/main  main
+        "#]]
+        .assert_eq(&stdout);
+    }
+
+    Ok(())
+}
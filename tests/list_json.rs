@@ -0,0 +1,109 @@
+use command_error::CommandExt;
+use git_prole::JSON_SCHEMA_VERSION;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use test_harness::GitProle;
+
+#[test]
+fn list_json() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--detach", "doggy"])
+        .status_checked()?;
+
+    prole.sh("
+        cd my-repo/puppy || exit
+        echo 'puppy doggy!' > README.md
+        ")?;
+
+    let stdout = prole
+        .cd_cmd("my-repo/main")
+        .args(["list", "--json"])
+        .output_checked_utf8()?
+        .stdout;
+
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(
+        json["schema_version"],
+        serde_json::Value::from(JSON_SCHEMA_VERSION)
+    );
+
+    let commit = prole
+        .git("my-repo/main")
+        .command()
+        .args(["rev-parse", "HEAD"])
+        .output_checked_utf8()?
+        .stdout;
+    let commit = commit.trim();
+
+    // Paths are absolute, so check their ends and remove them before comparing the rest.
+    let mut worktrees = json["worktrees"].clone();
+    for (worktree, name) in worktrees
+        .as_array_mut()
+        .unwrap()
+        .iter_mut()
+        .zip([".git", "doggy", "main", "puppy"])
+    {
+        let path = worktree.as_object_mut().unwrap().remove("path").unwrap();
+        let path = path.as_str().unwrap();
+        assert!(
+            path.ends_with(&format!("/my-repo/{name}")),
+            "Unexpected worktree path: {path}"
+        );
+    }
+
+    assert_eq!(
+        worktrees,
+        json!([
+            {
+                "head": "bare",
+                "commit": null,
+                "branch": null,
+                "upstream": null,
+                "is_main": true,
+                "dirty": false,
+                "locked": null,
+                "prunable": null,
+            },
+            {
+                "head": "detached",
+                "commit": commit,
+                "branch": null,
+                "upstream": null,
+                "is_main": false,
+                "dirty": false,
+                "locked": null,
+                "prunable": null,
+            },
+            {
+                "head": "branch",
+                "commit": commit,
+                "branch": "main",
+                "upstream": null,
+                "is_main": false,
+                "dirty": false,
+                "locked": null,
+                "prunable": null,
+            },
+            {
+                "head": "branch",
+                "commit": commit,
+                "branch": "puppy",
+                "upstream": "main",
+                "is_main": false,
+                "dirty": true,
+                "locked": null,
+                "prunable": null,
+            },
+        ])
+    );
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+
+#[test]
+fn list_nested_bare_repo() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.sh("
+        # An unrelated bare repository in a worktree subdirectory.
+        mkdir my-repo/main/sub
+        git init --bare my-repo/main/sub/fixture.git
+        ")?;
+
+    let stdout = prole
+        .cd_cmd("my-repo/main/sub")
+        .arg("list")
+        .output_checked_utf8()?
+        .stdout;
+
+    expect![[r#"
+        ~/my-repo/.git  bare  [main]
+        ~/my-repo/main  main
+    "#]]
+    .assert_eq(&stdout);
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+
+#[test]
+fn list_porcelain() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole.sh("
+        cd my-repo/puppy || exit
+        echo 'puppy doggy!' > README.md
+        ")?;
+
+    let root = prole.path("").canonicalize_utf8().unwrap();
+
+    let stdout = prole
+        .cd_cmd("my-repo/main")
+        .args(["list", "--porcelain"])
+        .output_checked_utf8()?
+        .stdout
+        .replace(root.as_str(), "$ROOT")
+        .replace('\0', "\n");
+
+    expect![[r#"
+        worktree $ROOT/my-repo/.git
+        bare
+
+        worktree $ROOT/my-repo/main
+        HEAD 4023d08019c45f462a9469778e78c3a1faad5013
+        branch refs/heads/main
+
+        worktree $ROOT/my-repo/puppy
+        HEAD 4023d08019c45f462a9469778e78c3a1faad5013
+        branch refs/heads/puppy
+        dirty
+
+    "#]]
+    .assert_eq(&stdout);
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+
+#[test]
+fn list_relative() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    let stdout = prole
+        .cd_cmd("my-repo/main")
+        .args(["list", "--relative"])
+        .output_checked_utf8()?
+        .stdout;
+
+    expect![[r#"
+        .git   bare         [main]
+        main   main
+        puppy  puppy  main
+    "#]]
+    .assert_eq(&stdout);
+
+    Ok(())
+}
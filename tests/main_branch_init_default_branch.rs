@@ -0,0 +1,34 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn main_branch_init_default_branch() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.sh("
+        cd my-repo/main || exit
+        git branch develop
+        git config --global init.defaultBranch develop
+        ")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            // `init.defaultBranch` is preferred over `branch_names`.
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .upstream("develop"),
+        ])
+        .assert();
+
+    Ok(())
+}
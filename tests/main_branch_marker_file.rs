@@ -0,0 +1,41 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn main_branch_marker_file() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.write_config(
+        r#"
+        branch_names = ["trunk"]
+        "#,
+    )?;
+
+    prole.sh("
+        cd my-repo/main || exit
+        git branch develop
+        git config --global --unset init.defaultBranch
+        echo develop > ../.git-prole-default-branch
+        ")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            // The marker file is used if nothing else is found.
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .upstream("develop"),
+        ])
+        .assert();
+
+    Ok(())
+}
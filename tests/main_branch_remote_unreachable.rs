@@ -0,0 +1,36 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn main_branch_remote_unreachable() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole.sh("
+        cd my-repo/main || exit
+        git branch develop
+        git config prole.defaultBranch develop
+        # No `origin/HEAD`, and the remote can't be reached to ask for its default branch.
+        git remote add origin ../../nonexistent
+        ")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            // The remote's default branch is unknown, so `prole.defaultBranch` is used.
+            WorktreeState::new("puppy")
+                .branch("puppy")
+                .upstream("develop"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn no_color() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    let stderr = prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .env("FORCE_COLOR", "1")
+        .output_checked_utf8()?
+        .stderr;
+    assert!(stderr.contains("\x1b["), "Colored by default: {stderr}");
+
+    let stderr = prole
+        .cd_cmd("my-repo/main")
+        .args(["--no-color", "add", "doggy"])
+        .env("FORCE_COLOR", "1")
+        .output_checked_utf8()?
+        .stderr;
+    assert!(!stderr.contains('\x1b'), "No ANSI codes: {stderr}");
+    assert!(stderr.contains("doggy"), "{stderr}");
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy").upstream("main"),
+            WorktreeState::new("doggy").branch("doggy").upstream("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
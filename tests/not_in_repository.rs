@@ -0,0 +1,31 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn not_in_repository() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.sh("mkdir puppy")?;
+    // Don't find any repositories the temporary directory is in.
+    let ceiling = prole.path("").canonicalize_utf8().unwrap();
+
+    let error = prole
+        .cd_cmd("puppy")
+        .args(["add", "doggy"])
+        .env("GIT_CEILING_DIRECTORIES", ceiling.as_str())
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("Not inside a Git repository"),
+        "`add` should refuse to run outside a repository: {error}"
+    );
+
+    // `config` doesn't need a repository.
+    prole
+        .cd_cmd("puppy")
+        .args(["config", "init", "config.toml"])
+        .env("GIT_CEILING_DIRECTORIES", ceiling.as_str())
+        .status_checked()?;
+
+    Ok(())
+}
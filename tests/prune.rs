@@ -0,0 +1,41 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn prune() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole.sh("
+        rm -rf my-repo/puppy
+        ")?;
+
+    let stderr = prole
+        .cd_cmd("my-repo/main")
+        .arg("prune")
+        .output_checked_utf8()?
+        .stderr;
+
+    assert!(
+        stderr.contains("~/my-repo/puppy: gitdir file points to non-existent location"),
+        "The prunable worktree and reason are listed: {stderr}"
+    );
+
+    assert!(!prole.path("my-repo/.git/worktrees/puppy").exists());
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn prune_dry_run() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole.sh("
+        rm -rf my-repo/puppy
+        ")?;
+
+    let stderr = prole
+        .cd_cmd("my-repo/main")
+        .args(["--dry-run", "prune", "--expire", "now"])
+        .output_checked_utf8()?
+        .stderr;
+
+    assert!(
+        stderr.contains("~/my-repo/puppy: gitdir file points to non-existent location"),
+        "The prunable worktree and reason are listed: {stderr}"
+    );
+    assert!(
+        stderr.contains("git worktree prune --expire now"),
+        "The prune command is printed: {stderr}"
+    );
+
+    assert!(prole.path("my-repo/.git/worktrees/puppy").exists());
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn prune_nothing() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    let stderr = prole
+        .cd_cmd("my-repo/main")
+        .arg("prune")
+        .output_checked_utf8()?
+        .stderr;
+
+    assert!(
+        stderr.contains("No worktrees to prune"),
+        "Nothing is pruned: {stderr}"
+    );
+
+    Ok(())
+}
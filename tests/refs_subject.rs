@@ -0,0 +1,22 @@
+use git_prole::GitLike;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn refs_subject() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+    prole.sh(r#"
+        cd my-repo || exit
+        git switch -c puppy
+        echo "cute" > puppy.txt
+        git add puppy.txt
+        git commit -m "Add puppy" -m "Puppies are very cute."
+        "#)?;
+
+    let git = prole.git("my-repo");
+    assert_eq!(git.refs().subject("HEAD")?, "Add puppy");
+    assert_eq!(git.refs().subject("main")?, "Initial commit");
+
+    Ok(())
+}
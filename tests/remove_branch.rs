@@ -0,0 +1,48 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn remove_branch() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "doggy"])
+        .status_checked()?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["remove", "--branch", "puppy"])
+        .status_checked()?;
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["remove", "../doggy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+        ])
+        .assert();
+
+    assert!(!prole.path("my-repo/puppy").exists());
+    assert!(!prole.path("my-repo/doggy").exists());
+
+    let branches = prole
+        .git("my-repo/main")
+        .command()
+        .args(["branch", "--format=%(refname:short)"])
+        .output_checked_utf8()?
+        .stdout;
+    assert_eq!(branches, "doggy\nmain\n");
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn remove_current_worktree() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    let error = prole
+        .cd_cmd("my-repo/puppy")
+        .args(["remove", "puppy"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("Refusing to remove the current worktree"),
+        "Current worktree: {error}"
+    );
+    assert!(prole.path("my-repo/puppy").exists());
+
+    prole
+        .cd_cmd("my-repo/puppy")
+        .args(["remove", "--force", "puppy"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn remove_dry_run() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    let stderr = prole
+        .cd_cmd("my-repo/main")
+        .args(["--dry-run", "remove", "--branch", "--force", "puppy"])
+        .output_checked_utf8()?
+        .stderr;
+    assert!(
+        stderr.contains("git worktree remove --force"),
+        "Prints `git worktree remove`: {stderr}"
+    );
+    assert!(
+        stderr.contains("git branch --delete puppy"),
+        "Prints `git branch --delete`: {stderr}"
+    );
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy").upstream("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
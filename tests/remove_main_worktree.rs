@@ -0,0 +1,28 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn remove_main_worktree() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["add", "../puppy"])
+        .status_checked()?;
+
+    let error = prole
+        .cd_cmd("puppy")
+        .args(["remove", "--force", "../my-repo"])
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("Refusing to remove the main worktree"),
+        "Main worktree: {error}"
+    );
+
+    assert!(prole.path("my-repo/README.md").exists());
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn status_copied() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+
+    prole.sh("
+        cd my-repo
+        seq 1 50 > numbers.txt
+        git add .
+        git commit -m 'Add numbers'
+
+        git config status.renames copies
+        cp numbers.txt more-numbers.txt
+        echo 51 >> numbers.txt
+        git add .
+        ")?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([WorktreeState::new("")
+            .is_main(true)
+            .status(["C  numbers.txt -> more-numbers.txt", "M  numbers.txt"])])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use git_prole::GitLike;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn status_v2() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_repo("my-repo")?;
+    prole.sh(r#"
+        cd my-repo || exit
+        git switch -c puppy --track main
+        echo "cute" > puppy.txt
+        git add puppy.txt
+        git commit -m "Add puppy"
+        git mv README.md DOGGY.md
+        echo "untracked" > doggy.txt
+        "#)?;
+
+    let status = prole.git("my-repo").status().get_v2()?;
+
+    assert_eq!(
+        status
+            .branch
+            .head
+            .as_ref()
+            .map(|branch| branch.branch_name()),
+        Some("puppy")
+    );
+    assert_eq!(status.branch.upstream.as_deref(), Some("main"));
+    assert_eq!(status.branch.ahead, Some(1));
+    assert_eq!(status.branch.behind, Some(0));
+
+    assert_eq!(
+        status
+            .entries
+            .iter()
+            .map(|entry| (entry.entry.to_string(), entry.score))
+            .collect::<Vec<_>>(),
+        vec![
+            ("R  README.md -> DOGGY.md".to_owned(), Some(100)),
+            ("?? doggy.txt".to_owned(), None),
+        ]
+    );
+    assert!(!status.is_clean());
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn verify() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole.cd_cmd("my-repo").arg("verify").status_checked()?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+
+#[test]
+fn verify_broken() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole.sh(r#"
+        cd my-repo || exit
+        echo "gitdir: $PWD/.git/worktrees/doggy" > puppy/.git
+        "#)?;
+
+    let error = prole
+        .cd_cmd("my-repo")
+        .arg("verify")
+        .output_checked_utf8()
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("Found broken worktree links"), "{error}");
+
+    // `verify` doesn't fix anything.
+    assert!(prole
+        .contents("my-repo/puppy/.git")?
+        .ends_with("worktrees/doggy\n"));
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use command_error::CommandExt;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn verify_fix() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "--multiple", "puppy", "silly"])
+        .status_checked()?;
+
+    prole.sh(r#"
+        cd my-repo || exit
+        mv puppy doggy
+        echo "$PWD/kitty/.git" > .git/worktrees/silly/gitdir
+        "#)?;
+
+    prole
+        .cd_cmd("my-repo")
+        .args(["verify", "--fix"])
+        .status_checked()?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("doggy").branch("puppy"),
+            WorktreeState::new("silly").branch("silly"),
+        ])
+        .assert();
+
+    prole.cd_cmd("my-repo").arg("verify").status_checked()?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+
+#[test]
+fn whereami() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    let root = prole.path("").canonicalize_utf8().unwrap();
+
+    let stdout = prole
+        .cd_cmd("my-repo/main")
+        .arg("whereami")
+        .output_checked_utf8()?
+        .stdout
+        .replace(root.as_str(), "$ROOT");
+
+    expect![[r#"
+        worktree $ROOT/my-repo/main
+        container $ROOT/my-repo
+        git_dir $ROOT/my-repo/.git
+        branch main
+    "#]]
+    .assert_eq(&stdout);
+
+    let stdout = prole
+        .cd_cmd("my-repo/main")
+        .args(["whereami", "--json"])
+        .output_checked_utf8()?
+        .stdout
+        .replace(root.as_str(), "$ROOT");
+
+    expect![[r#"
+        {
+          "schema_version": 1,
+          "worktree": "$ROOT/my-repo/main",
+          "container": "$ROOT/my-repo",
+          "git_dir": "$ROOT/my-repo/.git",
+          "branch": "main"
+        }
+    "#]]
+    .assert_eq(&stdout);
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use command_error::CommandExt;
+use git_prole::JSON_SCHEMA_VERSION;
+use pretty_assertions::assert_eq;
+use test_harness::GitProle;
+
+#[test]
+fn whereami_json_schema_version() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    let stdout = prole
+        .cd_cmd("my-repo/main")
+        .args(["whereami", "--json"])
+        .output_checked_utf8()?
+        .stdout;
+
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(
+        json["schema_version"],
+        serde_json::Value::from(JSON_SCHEMA_VERSION)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use command_error::CommandExt;
+use expect_test::expect;
+use test_harness::GitProle;
+
+#[test]
+fn whereami_porcelain() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+    prole.sh("cd my-repo/main && git switch --detach")?;
+
+    let root = prole.path("").canonicalize_utf8().unwrap();
+
+    // Unknown values are still printed, with an empty value.
+    let stdout = prole
+        .cd_cmd("my-repo/main")
+        .args(["whereami", "--porcelain"])
+        .output_checked_utf8()?
+        .stdout
+        .replace(root.as_str(), "$ROOT");
+
+    expect![[r#"
+        worktree $ROOT/my-repo/main
+        container $ROOT/my-repo
+        git_dir $ROOT/my-repo/.git
+        branch 
+    "#]]
+    .assert_eq(&stdout);
+
+    Ok(())
+}
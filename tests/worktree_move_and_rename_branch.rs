@@ -0,0 +1,37 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use git_prole::LocalBranchRef;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn worktree_move_and_rename_branch() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    prole
+        .git("my-repo/main")
+        .worktree()
+        .move_and_rename_branch(
+            &prole.path("my-repo/puppy"),
+            &prole.path("my-repo/doggy"),
+            &LocalBranchRef::new("puppy".into()),
+            &LocalBranchRef::new("doggy".into()),
+        )?;
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("doggy").branch("doggy").upstream("main"),
+        ])
+        .assert();
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use command_error::CommandExt;
+use git_prole::GitLike;
+use git_prole::LocalBranchRef;
+use test_harness::GitProle;
+use test_harness::WorktreeState;
+
+#[test]
+fn worktree_move_and_rename_branch_rollback() -> miette::Result<()> {
+    let prole = GitProle::new()?;
+    prole.setup_worktree_repo("my-repo")?;
+
+    prole
+        .cd_cmd("my-repo/main")
+        .args(["add", "puppy"])
+        .status_checked()?;
+
+    // `git worktree move` refuses to overwrite an existing file.
+    prole.sh("
+        echo 'a puppy is already here' > my-repo/doggy
+        ")?;
+
+    let git = prole.git("my-repo/main");
+    git.worktree()
+        .move_and_rename_branch(
+            &prole.path("my-repo/puppy"),
+            &prole.path("my-repo/doggy"),
+            &LocalBranchRef::new("puppy".into()),
+            &LocalBranchRef::new("doggy".into()),
+        )
+        .unwrap_err();
+
+    // The branch rename is rolled back.
+    assert!(git.branch().exists_local("puppy")?);
+    assert!(!git.branch().exists_local("doggy")?);
+
+    prole
+        .repo_state("my-repo")
+        .worktrees([
+            WorktreeState::new_bare(),
+            WorktreeState::new("main").branch("main"),
+            WorktreeState::new("puppy").branch("puppy").upstream("main"),
+        ])
+        .assert();
+
+    Ok(())
+}